@@ -0,0 +1,689 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Attribution of database pages to the tables and indexes owning them, and
+//! the `vfsstat_btree_io` table.
+//!
+//! Like the `dbstat` virtual table, the b-trees are found by walking
+//! `sqlite_schema` from page 1 and then every b-tree from its root page. The
+//! pages are read with the `sqlite_dbpage` virtual table of the querying
+//! connection, so they are seen as that connection sees them, including the
+//! changes that are still in the WAL, and no other file descriptor is opened
+//! on the database. The owners are kept until the data version of the
+//! database (`SQLITE_FCNTL_DATA_VERSION`) changes, so that querying the table
+//! again only reads the database after it was modified.
+
+use alloc::{
+    collections::BTreeMap,
+    ffi::CString,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+
+use std::sync::Mutex;
+
+use crate::{
+    pages::PageStats,
+    sqlite3ext::{
+        sqlite3, sqlite3_stmt, SQLITE_DONE, SQLITE_FCNTL_DATA_VERSION, SQLITE_OK, SQLITE_ROW,
+    },
+    table::{Row, Rows, Table, Value},
+    vfs::Vfs,
+    FileType, PathStats,
+};
+
+/// Size of the database header on page 1.
+const HEADER_SIZE: usize = 100;
+
+/// Page owner of pages that were not reached.
+const UNKNOWN: u32 = u32::MAX;
+
+/// B-tree page types.
+const INTERIOR_INDEX: u8 = 2;
+const INTERIOR_TABLE: u8 = 5;
+const LEAF_INDEX: u8 = 10;
+const LEAF_TABLE: u8 = 13;
+
+/// The owners of the pages of a database file.
+#[derive(Debug, Default)]
+pub struct PageOwners {
+    /// Name and type of each owner. The freelist has no name.
+    pub owners: Vec<(Option<String>, &'static str)>,
+    /// Index in `owners` of the owner of each page, by page number - 1
+    pages: Vec<u32>,
+}
+
+/// The [`PageOwners`] of a database file, as last read through a connection.
+#[derive(Debug, Default)]
+pub struct OwnersCache {
+    /// Connection and data version the owners were read with
+    entry: Mutex<Option<(usize, u32, Arc<PageOwners>)>>,
+}
+
+impl OwnersCache {
+    /// Returns the owners of the pages of the database `schema` of `db`,
+    /// reading its b-trees again unless they were last read through `db` at
+    /// the same data version.
+    ///
+    /// # Safety
+    ///
+    /// `db` must be an open connection, and no other thread may use it during
+    /// the call.
+    pub unsafe fn get(&self, db: *mut sqlite3, schema: &str) -> Result<Arc<PageOwners>, String> {
+        let mut reader = unsafe { Reader::open(db, schema) }?;
+        if reader.npages == 0 {
+            return Ok(Arc::default());
+        }
+        let version = reader.data_version()?;
+        if let Some((db_, version_, owners)) =
+            &*self.entry.lock().unwrap_or_else(|err| err.into_inner())
+        {
+            if *db_ == db as usize && *version_ == version {
+                return Ok(owners.clone());
+            }
+        }
+        let owners = Arc::new(PageOwners::read(&mut reader)?);
+        *self.entry.lock().unwrap_or_else(|err| err.into_inner()) =
+            Some((db as usize, version, owners.clone()));
+        Ok(owners)
+    }
+}
+
+impl PageOwners {
+    /// Reads the b-trees and freelist of the database of `reader`.
+    fn read(reader: &mut Reader) -> Result<Self, String> {
+        reader.prepare_pages()?;
+        let mut self_ = Self {
+            owners: Vec::new(),
+            pages: vec![UNKNOWN; reader.npages as usize],
+        };
+        let schema = self_.walk(reader, 1, Some("sqlite_schema".into()), "table", true)?;
+        for payload in schema {
+            let fields = if let Some(fields) = record(&payload) {
+                fields
+            } else {
+                continue;
+            };
+            let (type_, name, root) = match fields.as_slice() {
+                [Field::Text(type_), Field::Text(name), _, Field::Int(root), ..] => {
+                    (type_, name, *root)
+                }
+                _ => continue,
+            };
+            let type_ = match *type_ {
+                b"table" => "table",
+                b"index" => "index",
+                _ => continue,
+            };
+            if let Ok(root) = u32::try_from(root) {
+                let name = String::from_utf8_lossy(name).into_owned();
+                self_.walk(reader, root, Some(name), type_, false)?;
+            }
+        }
+        self_.freelist(reader)?;
+        Ok(self_)
+    }
+
+    /// Returns the index in `owners` of the owner of `pgno`.
+    pub fn owner(&self, pgno: u32) -> Option<usize> {
+        match self.pages.get(pgno.checked_sub(1)? as usize) {
+            Some(&owner) if owner != UNKNOWN => Some(owner as usize),
+            _ => None,
+        }
+    }
+
+    /// Assigns `pgno` to `owner` if it is a valid page without an owner.
+    fn claim(&mut self, pgno: u32, owner: u32) -> bool {
+        match pgno
+            .checked_sub(1)
+            .and_then(|i| self.pages.get_mut(i as usize))
+        {
+            Some(page) if *page == UNKNOWN => {
+                *page = owner;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Assigns the pages of the b-tree at `root` to a new owner, returning the
+    /// payloads of its table leaf cells if `payloads` is set.
+    fn walk(
+        &mut self,
+        reader: &mut Reader,
+        root: u32,
+        name: Option<String>,
+        type_: &'static str,
+        payloads: bool,
+    ) -> Result<Vec<Vec<u8>>, String> {
+        let owner = self.owners.len() as u32;
+        self.owners.push((name, type_));
+        let mut ret = Vec::new();
+        let mut stack = vec![root];
+        while let Some(pgno) = stack.pop() {
+            if !self.claim(pgno, owner) {
+                continue;
+            }
+            let data = reader.page(pgno)?;
+            let cells = if let Some(cells) = reader.cells(pgno, &data) {
+                cells
+            } else {
+                continue;
+            };
+            for cell in cells {
+                stack.extend(cell.child);
+                let (mut payload, len, mut next) = match cell.payload {
+                    Some(payload) => payload,
+                    None => continue,
+                };
+                while next != 0 && self.claim(next, owner) {
+                    let data = reader.page(next)?;
+                    if payloads {
+                        let rest = (len as usize).saturating_sub(payload.len());
+                        let end = reader.usable.min(4 + rest);
+                        payload.extend_from_slice(data.get(4..end).unwrap_or_default());
+                    }
+                    next = be32(&data, 0).unwrap_or(0);
+                }
+                if payloads && cell.leaf_table {
+                    ret.push(payload);
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Assigns the freelist trunk and leaf pages to a new owner.
+    fn freelist(&mut self, reader: &mut Reader) -> Result<(), String> {
+        let owner = self.owners.len() as u32;
+        self.owners.push((None, "freelist"));
+        let mut trunk = be32(&reader.page(1)?, 32).unwrap_or(0);
+        while self.claim(trunk, owner) {
+            let data = reader.page(trunk)?;
+            let leaves = be32(&data, 4).unwrap_or(0) as usize;
+            for i in 0..leaves.min((reader.usable - 8) / 4) {
+                if let Some(leaf) = be32(&data, 8 + 4 * i) {
+                    self.claim(leaf, owner);
+                }
+            }
+            trunk = be32(&data, 0).unwrap_or(0);
+        }
+        Ok(())
+    }
+}
+
+/// A b-tree cell.
+struct Cell {
+    /// Left child page of interior cells, or the right-most child page
+    child: Option<u32>,
+    /// Local payload, total payload size and first overflow page (or `0`)
+    payload: Option<(Vec<u8>, u64, u32)>,
+    leaf_table: bool,
+}
+
+/// Reads pages of a database through a connection.
+struct Reader {
+    db: *mut sqlite3,
+    schema: CString,
+    /// `SELECT data FROM sqlite_dbpage(<schema>) WHERE pgno = ?2`, once
+    /// [`prepare_pages`](Self::prepare_pages) was called
+    stmt: *mut sqlite3_stmt,
+    page_size: usize,
+    /// Page size without the reserved bytes at the end of each page
+    usable: usize,
+    npages: u32,
+}
+
+impl Reader {
+    /// Reads the page count of the database `schema` of `db`, which also lets
+    /// the connection notice the changes made by other connections.
+    unsafe fn open(db: *mut sqlite3, schema: &str) -> Result<Self, String> {
+        let schema = CString::new(schema).map_err(|err| err.to_string())?;
+        let mut self_ = Self {
+            db,
+            schema,
+            stmt: core::ptr::null_mut(),
+            page_size: 0,
+            usable: 0,
+            npages: 0,
+        };
+        self_.prepare(b"SELECT page_count FROM pragma_page_count(?1)\0")?;
+        let npages = self_.step()?;
+        self_.npages = if npages {
+            let n = unsafe { ((*crate::API).column_int64.unwrap())(self_.stmt, 0) };
+            u32::try_from(n).unwrap_or(u32::MAX)
+        } else {
+            0
+        };
+        self_.finalize();
+        Ok(self_)
+    }
+
+    /// Returns the data version of the database, which changes whenever its
+    /// content is changed, by any connection.
+    fn data_version(&self) -> Result<u32, String> {
+        let mut version: ::core::ffi::c_uint = 0;
+        let rc = unsafe {
+            ((*crate::API).file_control.unwrap())(
+                self.db,
+                self.schema.as_ptr(),
+                SQLITE_FCNTL_DATA_VERSION as _,
+                &mut version as *mut _ as _,
+            )
+        };
+        if rc != SQLITE_OK as _ {
+            return Err(self.error());
+        }
+        Ok(version)
+    }
+
+    /// Prepares the statement reading pages, and reads the page size from the
+    /// database header.
+    fn prepare_pages(&mut self) -> Result<(), String> {
+        let invalid = || "not a database file".to_string();
+        self.prepare(b"SELECT data FROM sqlite_dbpage(?1) WHERE pgno = ?2\0")?;
+        let header = self.page(1)?;
+        if header.len() < HEADER_SIZE || !header.starts_with(b"SQLite format 3\0") {
+            return Err(invalid());
+        }
+        self.page_size = match u16::from_be_bytes([header[16], header[17]]) {
+            1 => 65536,
+            n => usize::from(n),
+        };
+        self.usable = self.page_size.saturating_sub(usize::from(header[20]));
+        if !self.page_size.is_power_of_two() || self.page_size < 512 || self.usable < 480 {
+            return Err(invalid());
+        }
+        Ok(())
+    }
+
+    /// Replaces the statement with `sql`, binding the schema to `?1`.
+    fn prepare(&mut self, sql: &[u8]) -> Result<(), String> {
+        self.finalize();
+        let rc = unsafe {
+            ((*crate::API).prepare_v2.unwrap())(
+                self.db,
+                sql.as_ptr() as _,
+                -1,
+                &mut self.stmt,
+                core::ptr::null_mut(),
+            )
+        };
+        if rc != SQLITE_OK as _ {
+            return Err(self.error());
+        }
+        let rc = unsafe {
+            ((*crate::API).bind_text.unwrap())(
+                self.stmt,
+                1,
+                self.schema.as_ptr(),
+                -1,
+                crate::SQLITE_TRANSIENT(),
+            )
+        };
+        if rc != SQLITE_OK as _ {
+            return Err(self.error());
+        }
+        Ok(())
+    }
+
+    /// Steps the statement, returning whether it produced a row.
+    fn step(&mut self) -> Result<bool, String> {
+        match unsafe { ((*crate::API).step.unwrap())(self.stmt) } {
+            rc if rc == SQLITE_ROW as _ => Ok(true),
+            rc if rc == SQLITE_DONE as _ => Ok(false),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn error(&self) -> String {
+        let msg = unsafe { ((*crate::API).errmsg.unwrap())(self.db) };
+        if msg.is_null() {
+            return "out of memory".into();
+        }
+        unsafe { ::core::ffi::CStr::from_ptr(msg) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    fn finalize(&mut self) {
+        if !self.stmt.is_null() {
+            unsafe { ((*crate::API).finalize.unwrap())(self.stmt) };
+            self.stmt = core::ptr::null_mut();
+        }
+    }
+
+    fn page(&mut self, pgno: u32) -> Result<Vec<u8>, String> {
+        unsafe {
+            ((*crate::API).reset.unwrap())(self.stmt);
+            ((*crate::API).bind_int64.unwrap())(self.stmt, 2, pgno.into());
+        }
+        if !self.step()? {
+            return Err(format!("page {} not found", pgno));
+        }
+        let (data, len) = unsafe {
+            (
+                ((*crate::API).column_blob.unwrap())(self.stmt, 0) as *const u8,
+                ((*crate::API).column_bytes.unwrap())(self.stmt, 0),
+            )
+        };
+        if data.is_null() || len <= 0 {
+            return Ok(Vec::new());
+        }
+        Ok(unsafe { core::slice::from_raw_parts(data, len as usize) }.to_vec())
+    }
+
+    /// Parses the cells of a b-tree page, including a final cell with the
+    /// right-most child of interior pages. Returns `None` if the page is not a
+    /// valid b-tree page.
+    fn cells(&self, pgno: u32, data: &[u8]) -> Option<Vec<Cell>> {
+        let data = data.get(..self.usable)?;
+        let header = if pgno == 1 { HEADER_SIZE } else { 0 };
+        let kind = *data.get(header)?;
+        let interior = match kind {
+            INTERIOR_INDEX | INTERIOR_TABLE => true,
+            LEAF_INDEX | LEAF_TABLE => false,
+            _ => return None,
+        };
+        let ncells = be16(data, header + 3)?;
+        let pointers = header + if interior { 12 } else { 8 };
+        let mut cells = Vec::with_capacity(ncells + 1);
+        for i in 0..ncells {
+            let mut pos = be16(data, pointers + 2 * i)?;
+            let child = if interior {
+                pos += 4;
+                Some(be32(data, pos - 4)?)
+            } else {
+                None
+            };
+            if kind == INTERIOR_TABLE {
+                cells.push(Cell {
+                    child,
+                    payload: None,
+                    leaf_table: false,
+                });
+                continue;
+            }
+            let (len, n) = varint(data.get(pos..)?)?;
+            pos += n;
+            if kind == LEAF_TABLE {
+                pos += varint(data.get(pos..)?)?.1;
+            }
+            let local = self.local_size(kind, len);
+            let payload = data.get(pos..pos + local)?.to_vec();
+            let overflow = if (local as u64) < len {
+                be32(data, pos + local)?
+            } else {
+                0
+            };
+            cells.push(Cell {
+                child,
+                payload: Some((payload, len, overflow)),
+                leaf_table: kind == LEAF_TABLE,
+            });
+        }
+        if interior {
+            cells.push(Cell {
+                child: Some(be32(data, header + 8)?),
+                payload: None,
+                leaf_table: false,
+            });
+        }
+        Some(cells)
+    }
+
+    /// Returns how many bytes of a payload of `len` bytes are stored in the
+    /// cell itself, as described in the file format documentation.
+    fn local_size(&self, kind: u8, len: u64) -> usize {
+        let usable = self.usable as u64;
+        let max_local = if kind == LEAF_TABLE {
+            usable - 35
+        } else {
+            (usable - 12) * 64 / 255 - 23
+        };
+        if len <= max_local {
+            return len as usize;
+        }
+        let min_local = (usable - 12) * 32 / 255 - 23;
+        let local = min_local + (len - min_local) % (usable - 4);
+        if local <= max_local {
+            local as usize
+        } else {
+            min_local as usize
+        }
+    }
+}
+
+impl Drop for Reader {
+    fn drop(&mut self) {
+        self.finalize();
+    }
+}
+
+fn be16(data: &[u8], pos: usize) -> Option<usize> {
+    let bytes = data.get(pos..pos + 2)?;
+    Some(usize::from(u16::from_be_bytes([bytes[0], bytes[1]])))
+}
+
+fn be32(data: &[u8], pos: usize) -> Option<u32> {
+    let bytes = data.get(pos..pos + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Decodes a variable-length integer, returning it and its size in bytes.
+fn varint(data: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0_u64;
+    for i in 0..9 {
+        let byte = *data.get(i)?;
+        if i == 8 {
+            return Some(((value << 8) | u64::from(byte), 9));
+        }
+        value = (value << 7) | u64::from(byte & 0x7f);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// A column of a record, as far as the schema is concerned.
+#[derive(Debug, PartialEq)]
+enum Field<'a> {
+    Int(i64),
+    Text(&'a [u8]),
+    Other,
+}
+
+/// Decodes a record.
+fn record(payload: &[u8]) -> Option<Vec<Field<'_>>> {
+    let (header_len, mut pos) = varint(payload)?;
+    let mut body = usize::try_from(header_len).ok()?;
+    let mut fields = Vec::new();
+    while pos < header_len as usize {
+        let (serial_type, n) = varint(payload.get(pos..)?)?;
+        pos += n;
+        let size = match serial_type {
+            0 | 8 | 9 => 0,
+            1..=4 => serial_type as usize,
+            5 => 6,
+            6 | 7 => 8,
+            10 | 11 => return None,
+            _ => (serial_type as usize - 12) / 2,
+        };
+        let bytes = payload.get(body..body + size)?;
+        body += size;
+        fields.push(match serial_type {
+            1..=6 => {
+                // Sign-extend the big-endian integer.
+                let value = bytes
+                    .iter()
+                    .fold(i64::from(bytes[0] as i8) >> 8, |acc, &b| {
+                        (acc << 8) | i64::from(b)
+                    });
+                Field::Int(value)
+            }
+            8 => Field::Int(0),
+            9 => Field::Int(1),
+            n if n >= 13 && n % 2 == 1 => Field::Text(bytes),
+            _ => Field::Other,
+        });
+    }
+    Some(fields)
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_btree_io\0",
+    schema: b"CREATE TABLE x(path,name,type,reads,writes,bytes)\0",
+    rows: Rows::Connection(rows),
+};
+
+/// Returns the schema name of each database file attached to `db`, by path.
+unsafe fn schemas(db: *mut sqlite3) -> BTreeMap<String, String> {
+    let mut ret = BTreeMap::new();
+    let mut stmt: *mut sqlite3_stmt = core::ptr::null_mut();
+    let rc = unsafe {
+        ((*crate::API).prepare_v2.unwrap())(
+            db,
+            b"SELECT name, file FROM pragma_database_list\0".as_ptr() as _,
+            -1,
+            &mut stmt,
+            core::ptr::null_mut(),
+        )
+    };
+    if rc != SQLITE_OK as _ {
+        return ret;
+    }
+    let text = |i| {
+        let ptr = unsafe { ((*crate::API).column_text.unwrap())(stmt, i) };
+        if ptr.is_null() {
+            String::new()
+        } else {
+            unsafe { ::core::ffi::CStr::from_ptr(ptr as _) }
+                .to_string_lossy()
+                .into_owned()
+        }
+    };
+    while unsafe { ((*crate::API).step.unwrap())(stmt) } == SQLITE_ROW as _ {
+        let (name, file) = (text(0), text(1));
+        if !file.is_empty() {
+            ret.insert(file, name);
+        }
+    }
+    unsafe { ((*crate::API).finalize.unwrap())(stmt) };
+    ret
+}
+
+/// One row per main database and b-tree with tracked accesses, and one row
+/// with a `NULL` name and type for accesses that could not be attributed.
+///
+/// Only the databases attached to `db` can be attributed, and reading them
+/// through `db` is I/O that is counted like any other.
+fn rows(vfs_: &Vfs, db: *mut sqlite3) -> Result<Vec<Row>, String> {
+    // Release the lock before reading the databases.
+    let paths: Vec<(String, Arc<PathStats>)> = vfs_
+        .paths
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .iter()
+        .filter(|(_, path_stats)| path_stats.filetype == FileType::Main)
+        .map(|(path, path_stats)| (path.clone(), path_stats.clone()))
+        .collect();
+    let schemas = unsafe { schemas(db) };
+    let mut rows = Vec::new();
+    for (path, path_stats) in paths {
+        let (pages, untracked) = {
+            let pages = path_stats
+                .pages
+                .lock()
+                .unwrap_or_else(|err| err.into_inner());
+            let pages_ = pages
+                .pages
+                .iter()
+                .map(|(k, v)| (*k, *v))
+                .collect::<Vec<_>>();
+            (pages_, pages.untracked)
+        };
+        if pages.is_empty() && untracked.reads == 0 && untracked.writes == 0 {
+            continue;
+        }
+        let owners = match schemas.get(&path) {
+            Some(schema) => unsafe { path_stats.owners.get(db, schema) }
+                .map_err(|err| format!("could not read the b-trees of {}: {}", path, err))?,
+            None => Arc::default(),
+        };
+        let mut totals: BTreeMap<Option<usize>, PageStats> = BTreeMap::new();
+        totals.entry(None).or_default().add(&untracked);
+        for (pgno, page) in &pages {
+            totals.entry(owners.owner(*pgno)).or_default().add(page);
+        }
+        for (owner, stats) in totals {
+            if stats.reads == 0 && stats.writes == 0 {
+                continue;
+            }
+            let (name, type_) = match owner.map(|i| &owners.owners[i]) {
+                Some((name, type_)) => (
+                    name.as_ref().map_or(Value::Null, |n| n.to_string().into()),
+                    (*type_).into(),
+                ),
+                None => (Value::Null, Value::Null),
+            };
+            rows.push(vec![
+                path.clone().into(),
+                name,
+                type_,
+                stats.reads.into(),
+                stats.writes.into(),
+                (stats.bytes_in + stats.bytes_out).into(),
+            ]);
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint() {
+        assert_eq!(varint(&[0x00]), Some((0, 1)));
+        assert_eq!(varint(&[0x7f, 0xff]), Some((0x7f, 1)));
+        assert_eq!(varint(&[0x81, 0x00]), Some((0x80, 2)));
+        assert_eq!(varint(&[0x82, 0x81, 0x7f]), Some((0x80ff, 3)));
+        assert_eq!(varint(&[0xff; 9]), Some((u64::MAX, 9)));
+        assert_eq!(varint(&[0x81]), None);
+        assert_eq!(varint(&[]), None);
+    }
+
+    #[test]
+    fn test_record() {
+        // ('table', 't', 't', 2, NULL): header of 6 bytes, then the values.
+        let payload = [
+            6, 23, 15, 15, 1, 0, b't', b'a', b'b', b'l', b'e', b't', b't', 2,
+        ];
+        assert_eq!(
+            record(&payload),
+            Some(vec![
+                Field::Text(b"table"),
+                Field::Text(b"t"),
+                Field::Text(b"t"),
+                Field::Int(2),
+                Field::Other,
+            ])
+        );
+        // Negative 16-bit integer, the constants 0 and 1, and a blob.
+        assert_eq!(
+            record(&[5, 2, 8, 9, 14, 0xff, 0xfe, 0xab]),
+            Some(vec![
+                Field::Int(-2),
+                Field::Int(0),
+                Field::Int(1),
+                Field::Other
+            ])
+        );
+        // Truncated body
+        assert_eq!(record(&[3, 23, 1]), None);
+    }
+}
@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Per-commit I/O profiles, and the `vfsstat_commits` and
+//! `vfsstat_commit_histograms` tables.
+//!
+//! A write transaction of a database starts when its main file is locked
+//! `RESERVED` or higher, when the WAL `WRITER` lock is taken, or with
+//! `SQLITE_FCNTL_BEGIN_ATOMIC_WRITE`. It is recorded as a commit when
+//! `SQLITE_FCNTL_COMMIT_PHASETWO` is received, and forgotten if the main file
+//! is unlocked below `RESERVED` or closed. Each transaction belongs to the
+//! main file handle that started it, so that other connections of the same
+//! database cannot end or replace it while it holds the write lock.
+
+use alloc::{collections::VecDeque, string::String, vec, vec::Vec};
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::{
+    sync::Mutex,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    histogram::Histogram,
+    table::{Row, Rows, Table, Value},
+    vfs::Vfs,
+    FileType,
+};
+
+/// A write transaction in progress.
+#[derive(Clone, Copy, Debug)]
+pub struct Pending {
+    start: Instant,
+    /// Seconds since the Unix epoch
+    started: f64,
+    /// Bytes written to the main database, journal and WAL
+    bytes: [u64; 3],
+    syncs: u64,
+    sync_ns: u64,
+    /// Whether the transaction used a batch atomic write
+    atomic: bool,
+}
+
+impl Pending {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            started: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0.0, |d| d.as_secs_f64()),
+            bytes: [0; 3],
+            syncs: 0,
+            sync_ns: 0,
+            atomic: false,
+        }
+    }
+
+    /// Adds `n` bytes written to a file of type `filetype`.
+    pub fn write(&mut self, filetype: FileType, n: u64) {
+        let idx = match filetype {
+            FileType::Main => 0,
+            FileType::Journal => 1,
+            FileType::Wal => 2,
+            _ => return,
+        };
+        self.bytes[idx] += n;
+    }
+
+    pub fn sync(&mut self, ns: u64) {
+        self.syncs += 1;
+        self.sync_ns += ns;
+    }
+
+    pub fn set_atomic(&mut self) {
+        self.atomic = true;
+    }
+}
+
+impl Default for Pending {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The write transaction in progress on a database, if any, and the main file
+/// handle it belongs to.
+#[derive(Debug, Default)]
+pub struct PendingCommit {
+    inner: Mutex<Option<Owned>>,
+}
+
+#[derive(Debug)]
+struct Owned {
+    owner: usize,
+    pending: Pending,
+    /// Whether the owner released the WAL `WRITER` lock, which happens before
+    /// `SQLITE_FCNTL_COMMIT_PHASETWO`
+    released: bool,
+}
+
+impl PendingCommit {
+    /// Starts a write transaction of `owner`, unless another handle has one in
+    /// progress.
+    pub fn begin(&self, owner: usize) {
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        match &*inner {
+            Some(other) if other.owner != owner && !other.released => {}
+            _ => {
+                *inner = Some(Owned {
+                    owner,
+                    pending: Pending::new(),
+                    released: false,
+                })
+            }
+        }
+    }
+
+    /// Marks the write transaction of `owner` as a batch atomic write, starting
+    /// it if needed.
+    pub fn set_atomic(&self, owner: usize) {
+        self.begin(owner);
+        self.with_owned(owner, |owned| owned.pending.set_atomic());
+    }
+
+    /// Lets another handle start a write transaction once `owner` released the
+    /// WAL `WRITER` lock, while still allowing `owner` to commit its own.
+    pub fn release(&self, owner: usize) {
+        self.with_owned(owner, |owned| owned.released = true);
+    }
+
+    /// Forgets the write transaction of `owner`, if it has one.
+    pub fn end(&self, owner: usize) {
+        self.take(owner);
+    }
+
+    /// Removes and returns the write transaction of `owner`, if it has one.
+    pub fn take(&self, owner: usize) -> Option<Pending> {
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        match &*inner {
+            Some(owned) if owned.owner == owner => inner.take().map(|owned| owned.pending),
+            _ => None,
+        }
+    }
+
+    /// Calls `f` with the write transaction in progress, whichever handle it
+    /// belongs to.
+    pub fn with(&self, f: impl FnOnce(&mut Pending)) {
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(owned) = &mut *inner {
+            f(&mut owned.pending);
+        }
+    }
+
+    fn with_owned(&self, owner: usize, f: impl FnOnce(&mut Owned)) {
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        match &mut *inner {
+            Some(owned) if owned.owner == owner => f(owned),
+            _ => {}
+        }
+    }
+}
+
+/// A finished commit.
+#[derive(Clone, Debug)]
+pub struct Commit {
+    pub id: u64,
+    pub path: String,
+    /// Seconds since the Unix epoch
+    pub started: f64,
+    pub duration_ns: u64,
+    pub main_bytes: u64,
+    pub journal_bytes: u64,
+    pub wal_bytes: u64,
+    pub syncs: u64,
+    pub sync_ns: u64,
+    pub atomic: bool,
+}
+
+/// Recent commits, and histograms of all commits.
+#[derive(Debug, Default)]
+pub struct Commits {
+    history: Mutex<VecDeque<Commit>>,
+    next_id: AtomicU64,
+    /// Commit durations, in nanoseconds
+    pub duration_ns: Histogram,
+    /// Bytes written per commit
+    pub bytes: Histogram,
+    /// Syncs per commit
+    pub syncs: Histogram,
+}
+
+impl Commits {
+    /// Records `pending` as a commit of `path`, keeping at most `max` commits
+    /// in the history.
+    pub fn record(&self, path: &str, pending: Pending, max: usize) {
+        let duration_ns = u64::try_from(pending.start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let bytes: u64 = pending.bytes.iter().sum();
+        self.duration_ns.record(duration_ns);
+        self.bytes.record(bytes);
+        self.syncs.record(pending.syncs);
+        let commit = Commit {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed) + 1,
+            path: path.into(),
+            started: pending.started,
+            duration_ns,
+            main_bytes: pending.bytes[0],
+            journal_bytes: pending.bytes[1],
+            wal_bytes: pending.bytes[2],
+            syncs: pending.syncs,
+            sync_ns: pending.sync_ns,
+            atomic: pending.atomic,
+        };
+        let mut history = self.history.lock().unwrap_or_else(|err| err.into_inner());
+        history.push_back(commit);
+        while history.len() > max {
+            history.pop_front();
+        }
+    }
+
+    pub fn reset(&self) {
+        self.history
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+        self.duration_ns.reset();
+        self.bytes.reset();
+        self.syncs.reset();
+    }
+
+    /// Returns a copy of the history, oldest first.
+    pub fn snapshot(&self) -> Vec<Commit> {
+        let history = self.history.lock().unwrap_or_else(|err| err.into_inner());
+        history.iter().cloned().collect()
+    }
+
+    /// Returns the histograms with their metric names.
+    pub fn histograms(&self) -> [(&'static str, &Histogram); 3] {
+        [
+            ("duration_ns", &self.duration_ns),
+            ("bytes", &self.bytes),
+            ("syncs", &self.syncs),
+        ]
+    }
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_commits\0",
+    schema: b"CREATE TABLE x(id,path,started,duration_ns,main_bytes,journal_bytes,wal_bytes,syncs,sync_ns,atomic)\0",
+    rows: Rows::Scan(rows),
+};
+
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    vfs_.commits
+        .snapshot()
+        .into_iter()
+        .map(|commit| {
+            vec![
+                commit.id.into(),
+                commit.path.into(),
+                Value::Real(commit.started),
+                commit.duration_ns.into(),
+                commit.main_bytes.into(),
+                commit.journal_bytes.into(),
+                commit.wal_bytes.into(),
+                commit.syncs.into(),
+                commit.sync_ns.into(),
+                Value::Int(commit.atomic.into()),
+            ]
+        })
+        .collect()
+}
+
+pub static HISTOGRAMS_TABLE: Table = Table {
+    name: b"vfsstat_commit_histograms\0",
+    schema: b"CREATE TABLE x(metric,bucket_lo,bucket_hi,count)\0",
+    rows: Rows::Scan(histogram_rows),
+};
+
+/// One row per non-empty bucket.
+fn histogram_rows(vfs_: &Vfs) -> Vec<Row> {
+    let mut rows = Vec::new();
+    for (metric, histogram) in vfs_.commits.histograms() {
+        for (bucket, count) in histogram.snapshot().iter().enumerate() {
+            if *count == 0 {
+                continue;
+            }
+            let (lo, hi) = Histogram::bounds(bucket);
+            rows.push(vec![metric.into(), lo.into(), hi.into(), (*count).into()]);
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_commit_survives_other_connections() {
+        // Main file handles of two connections of the same database.
+        let (writer, reader) = (1, 2);
+        let commit = PendingCommit::default();
+        // BEGIN IMMEDIATE; INSERT on the writer
+        commit.begin(writer);
+        commit.with(|pending| pending.write(FileType::Main, 4096));
+        // SELECT on the reader, which unlocks below RESERVED when done
+        commit.end(reader);
+        // A write lock cannot be granted to the reader, but must not replace
+        // the transaction if it were.
+        commit.begin(reader);
+        assert!(commit.take(reader).is_none());
+        // COMMIT on the writer
+        let commits = Commits::default();
+        commits.record("test.db", commit.take(writer).unwrap(), 10);
+        let history = commits.snapshot();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].main_bytes, 4096);
+        assert!(commit.take(writer).is_none());
+
+        // Once the WAL WRITER lock is released, the writer can still commit,
+        // unless another transaction started first.
+        commit.begin(writer);
+        commit.release(writer);
+        assert!(commit.take(writer).is_some());
+        commit.begin(writer);
+        commit.release(writer);
+        commit.begin(reader);
+        assert!(commit.take(writer).is_none());
+        assert!(commit.take(reader).is_some());
+    }
+}
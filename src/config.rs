@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Runtime settings of a [`Vfs`](crate::vfs::Vfs).
+
+use alloc::{format, string::String};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use log::debug;
+
+/// Settings that can be changed while the VFS is in use.
+///
+/// Every setting has a key, used by [`Config::set`] and [`Config::get`], and
+/// an environment variable `VFSSTAT_<KEY>` that overrides its default when
+/// the VFS is created.
+#[derive(Debug)]
+pub struct Config {
+    /// Maximum number of database pages tracked by the page heatmap, across
+    /// all files.
+    pub max_pages: AtomicUsize,
+    /// Maximum number of distinct SQL statements with I/O statistics.
+    pub max_statements: AtomicUsize,
+    /// Maximum number of commits kept in the commit history.
+    pub max_commits: AtomicUsize,
+    /// Whether the statements of connections opened from now on are traced,
+    /// `0` or `1`.
+    pub trace_statements: AtomicUsize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_pages: AtomicUsize::new(65536),
+            max_statements: AtomicUsize::new(1024),
+            max_commits: AtomicUsize::new(1000),
+            trace_statements: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl Config {
+    /// Setting keys.
+    pub const KEYS: &'static [&'static str] = &[
+        "max_pages",
+        "max_statements",
+        "max_commits",
+        "trace_statements",
+    ];
+
+    /// Returns the default settings, overridden by any `VFSSTAT_<KEY>`
+    /// environment variables.
+    pub fn from_env() -> Self {
+        let self_ = Self::default();
+        for key in Self::KEYS {
+            let var = format!("VFSSTAT_{}", key.to_ascii_uppercase());
+            if let Ok(value) = std::env::var(&var) {
+                if let Err(err) = self_.set(key, &value) {
+                    debug!("Ignoring {}: {}", var, err);
+                }
+            }
+        }
+        self_
+    }
+
+    fn setting(&self, key: &str) -> Result<&AtomicUsize, String> {
+        match key {
+            "max_pages" => Ok(&self.max_pages),
+            "max_statements" => Ok(&self.max_statements),
+            "max_commits" => Ok(&self.max_commits),
+            "trace_statements" => Ok(&self.trace_statements),
+            _ => Err(format!("Unknown setting {:?}", key)),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Result<usize, String> {
+        Ok(self.setting(key)?.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, key: &str, value: &str) -> Result<(), String> {
+        let setting = self.setting(key)?;
+        let value: usize = value
+            .trim()
+            .parse()
+            .map_err(|err| format!("Invalid value {:?} for {}: {}", value, key, err))?;
+        setting.store(value, Ordering::Relaxed);
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Per-connection statistics.
+//!
+//! A main database file learns its connection from `SQLITE_FCNTL_PDB`. Other
+//! files are attributed to the current connection of the thread that opens
+//! them, and VFS methods to the current connection of the thread calling
+//! them. The current connection is the one whose statement is running on the
+//! thread, as followed by [`statements`](crate::statements) when statements
+//! are traced, or else the connection whose main database file was last used
+//! on the thread, which is the one opening its journal, WAL and temporary
+//! files since SQLite always reaches the main database first.
+//!
+//! The statistics of a connection are forgotten when it is closed, which
+//! [`closed`] learns from the destructor of the `vtabstat` module.
+
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use core::cell::RefCell;
+use std::sync::Mutex;
+
+use crate::{sqlite3ext::sqlite3, vfs::Vfs, FileStats};
+
+/// [`FileStats`] of each open connection, keyed by its address.
+#[derive(Debug, Default)]
+pub struct Connections {
+    entries: Mutex<BTreeMap<usize, Arc<FileStats>>>,
+}
+
+impl Connections {
+    /// Returns the statistics of `db`, creating them if needed.
+    pub fn get(&self, db: *mut sqlite3) -> Arc<FileStats> {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries.entry(db as usize).or_default().clone()
+    }
+
+    pub fn reset(&self) {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        for stats in entries.values() {
+            stats.reset();
+        }
+    }
+
+    /// Forgets `db`, which is being closed.
+    pub fn remove(&self, db: *mut sqlite3) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(&(db as usize));
+    }
+}
+
+/// Forgets the statistics of the connection `db` in every stat VFS.  Used as
+/// the destructor of the client data of a module, which SQLite calls when
+/// `db` is closed.
+///
+/// # Safety
+///
+/// Only SQLite may call this, with the client data `db` given to
+/// `sqlite3_create_module_v2()`.
+pub unsafe extern "C" fn closed(db: *mut ::core::ffi::c_void) {
+    for vfs_ in Vfs::instances() {
+        vfs_.connections.remove(db as *mut sqlite3);
+    }
+}
+
+std::thread_local! {
+    /// Connections of the statements running on this thread, by
+    /// `sqlite3_stmt` address.
+    static RUNNING: RefCell<Vec<(usize, Arc<FileStats>)>> = const { RefCell::new(Vec::new()) };
+    /// Connection whose main database file was last used on this thread
+    static LAST: RefCell<Option<Arc<FileStats>>> = const { RefCell::new(None) };
+}
+
+/// Returns the statistics of the current connection of this thread, if any.
+pub fn current() -> Option<Arc<FileStats>> {
+    let running = RUNNING
+        .try_with(|running| running.borrow().last().map(|(_, stats)| stats.clone()))
+        .ok()
+        .flatten();
+    running.or_else(|| LAST.try_with(|last| last.borrow().clone()).ok().flatten())
+}
+
+/// Calls `f` with the statistics of the current connection of this thread, if
+/// any.
+pub fn with_current(f: impl FnOnce(&FileStats)) {
+    if let Some(stats) = current() {
+        f(&stats);
+    }
+}
+
+/// Makes the connection of `stats` the current connection of this thread when
+/// no statement is running, after its main database file was used.
+///
+/// # Safety
+///
+/// `stats` must come from [`Arc::into_raw`] and still be owned by the caller.
+pub unsafe fn main_used(stats: *const FileStats) {
+    let _ = LAST.try_with(|last| {
+        let mut last = last.borrow_mut();
+        if last.as_ref().map(Arc::as_ptr) != Some(stats) {
+            unsafe { Arc::increment_strong_count(stats) };
+            *last = Some(unsafe { Arc::from_raw(stats) });
+        }
+    });
+}
+
+/// Marks `stmt` as running on this thread, on behalf of `stats`.
+pub fn statement_started(stmt: usize, stats: Arc<FileStats>) {
+    let _ = RUNNING.try_with(|running| {
+        let mut running = running.borrow_mut();
+        running.retain(|(s, _)| *s != stmt);
+        running.push((stmt, stats));
+    });
+}
+
+pub fn statement_finished(stmt: usize) {
+    let _ = RUNNING.try_with(|running| {
+        running.borrow_mut().retain(|(s, _)| *s != stmt);
+    });
+}
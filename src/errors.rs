@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Non-`SQLITE_OK` return codes of the parent VFS, and the `vfsstat_errors`
+//! table.
+
+use alloc::{collections::BTreeMap, format, string::String, vec, vec::Vec};
+use std::{
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    table::{Row, Rows, Table, Value},
+    vfs::Vfs,
+    FileType,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorEntry {
+    /// Number of times the code was returned
+    pub count: u64,
+    /// When the code was last returned, in seconds since the Unix epoch
+    pub last_seen: f64,
+}
+
+/// Return codes keyed by file type, operation name and extended result code.
+#[derive(Debug, Default)]
+pub struct Errors {
+    entries: Mutex<BTreeMap<(usize, &'static str, ::core::ffi::c_int), ErrorEntry>>,
+}
+
+impl Errors {
+    /// Records `rc` as returned by `op` on a file of type `filetype`.
+    pub fn record(&self, filetype: FileType, op: &'static str, rc: ::core::ffi::c_int) {
+        let last_seen = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0.0, |d| d.as_secs_f64());
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        let entry = entries
+            .entry((filetype as usize, op, rc))
+            .or_insert(ErrorEntry {
+                count: 0,
+                last_seen,
+            });
+        entry.count += 1;
+        entry.last_seen = last_seen;
+    }
+
+    pub fn reset(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+
+    /// Returns a copy of all entries.
+    pub fn snapshot(&self) -> Vec<(FileType, &'static str, ::core::ffi::c_int, ErrorEntry)> {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries
+            .iter()
+            .map(|(&(filetype, op, rc), entry)| (FileType::ALL[filetype], op, rc, *entry))
+            .collect()
+    }
+}
+
+/// Returns the symbolic name of an (extended) result code.
+pub fn rc_name(rc: ::core::ffi::c_int) -> String {
+    let primary = match rc & 0xff {
+        0 => "SQLITE_OK",
+        1 => "SQLITE_ERROR",
+        2 => "SQLITE_INTERNAL",
+        3 => "SQLITE_PERM",
+        4 => "SQLITE_ABORT",
+        5 => "SQLITE_BUSY",
+        6 => "SQLITE_LOCKED",
+        7 => "SQLITE_NOMEM",
+        8 => "SQLITE_READONLY",
+        9 => "SQLITE_INTERRUPT",
+        10 => "SQLITE_IOERR",
+        11 => "SQLITE_CORRUPT",
+        12 => "SQLITE_NOTFOUND",
+        13 => "SQLITE_FULL",
+        14 => "SQLITE_CANTOPEN",
+        15 => "SQLITE_PROTOCOL",
+        16 => "SQLITE_EMPTY",
+        17 => "SQLITE_SCHEMA",
+        18 => "SQLITE_TOOBIG",
+        19 => "SQLITE_CONSTRAINT",
+        20 => "SQLITE_MISMATCH",
+        21 => "SQLITE_MISUSE",
+        22 => "SQLITE_NOLFS",
+        23 => "SQLITE_AUTH",
+        24 => "SQLITE_FORMAT",
+        25 => "SQLITE_RANGE",
+        26 => "SQLITE_NOTADB",
+        27 => "SQLITE_NOTICE",
+        28 => "SQLITE_WARNING",
+        100 => "SQLITE_ROW",
+        101 => "SQLITE_DONE",
+        _ => return format!("{}", rc),
+    };
+    let extended = rc >> 8;
+    if extended == 0 {
+        return primary.into();
+    }
+    let suffix = match (rc & 0xff, extended) {
+        (1, 1) => "MISSING_COLLSEQ",
+        (1, 2) => "RETRY",
+        (1, 3) => "SNAPSHOT",
+        (4, 2) => "ROLLBACK",
+        (5, 1) => "RECOVERY",
+        (5, 2) => "SNAPSHOT",
+        (5, 3) => "TIMEOUT",
+        (6, 1) => "SHAREDCACHE",
+        (6, 2) => "VTAB",
+        (8, 1) => "RECOVERY",
+        (8, 2) => "CANTLOCK",
+        (8, 3) => "ROLLBACK",
+        (8, 4) => "DBMOVED",
+        (8, 5) => "CANTINIT",
+        (8, 6) => "DIRECTORY",
+        (10, 1) => "READ",
+        (10, 2) => "SHORT_READ",
+        (10, 3) => "WRITE",
+        (10, 4) => "FSYNC",
+        (10, 5) => "DIR_FSYNC",
+        (10, 6) => "TRUNCATE",
+        (10, 7) => "FSTAT",
+        (10, 8) => "UNLOCK",
+        (10, 9) => "RDLOCK",
+        (10, 10) => "DELETE",
+        (10, 11) => "BLOCKED",
+        (10, 12) => "NOMEM",
+        (10, 13) => "ACCESS",
+        (10, 14) => "CHECKRESERVEDLOCK",
+        (10, 15) => "LOCK",
+        (10, 16) => "CLOSE",
+        (10, 17) => "DIR_CLOSE",
+        (10, 18) => "SHMOPEN",
+        (10, 19) => "SHMSIZE",
+        (10, 20) => "SHMLOCK",
+        (10, 21) => "SHMMAP",
+        (10, 22) => "SEEK",
+        (10, 23) => "DELETE_NOENT",
+        (10, 24) => "MMAP",
+        (10, 25) => "GETTEMPPATH",
+        (10, 26) => "CONVPATH",
+        (10, 27) => "VNODE",
+        (10, 28) => "AUTH",
+        (10, 29) => "BEGIN_ATOMIC",
+        (10, 30) => "COMMIT_ATOMIC",
+        (10, 31) => "ROLLBACK_ATOMIC",
+        (10, 32) => "DATA",
+        (10, 33) => "CORRUPTFS",
+        (11, 1) => "VTAB",
+        (11, 2) => "SEQUENCE",
+        (11, 3) => "INDEX",
+        (14, 1) => "NOTEMPDIR",
+        (14, 2) => "ISDIR",
+        (14, 3) => "FULLPATH",
+        (14, 4) => "CONVPATH",
+        (14, 6) => "SYMLINK",
+        (27, 1) => "RECOVER_WAL",
+        (27, 2) => "RECOVER_ROLLBACK",
+        (28, 1) => "AUTOINDEX",
+        (23, 1) => "USER",
+        (0, 1) => "LOAD_PERMANENTLY",
+        (0, 2) => "SYMLINK",
+        _ => return format!("{}_{}", primary, extended),
+    };
+    format!("{}_{}", primary, suffix)
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_errors\0",
+    schema: b"CREATE TABLE x(file,op,rc,rc_name,count,last_seen)\0",
+    rows: Rows::Scan(rows),
+};
+
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    vfs_.errors
+        .snapshot()
+        .into_iter()
+        .map(|(filetype, op, rc, entry)| {
+            vec![
+                filetype.name().into(),
+                op.into(),
+                Value::Int(rc.into()),
+                rc_name(rc).into(),
+                entry.count.into(),
+                Value::Real(entry.last_seen),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rc_name() {
+        use crate::sqlite3ext::{
+            SQLITE_BUSY, SQLITE_BUSY_TIMEOUT, SQLITE_CANTOPEN_ISDIR, SQLITE_DONE, SQLITE_IOERR,
+            SQLITE_IOERR_CORRUPTFS, SQLITE_IOERR_SHORT_READ,
+        };
+
+        assert_eq!(rc_name(SQLITE_BUSY as _), "SQLITE_BUSY");
+        assert_eq!(rc_name(SQLITE_DONE as _), "SQLITE_DONE");
+        assert_eq!(rc_name(SQLITE_BUSY_TIMEOUT as _), "SQLITE_BUSY_TIMEOUT");
+        assert_eq!(
+            rc_name(SQLITE_IOERR_SHORT_READ as _),
+            "SQLITE_IOERR_SHORT_READ"
+        );
+        assert_eq!(
+            rc_name(SQLITE_IOERR_CORRUPTFS as _),
+            "SQLITE_IOERR_CORRUPTFS"
+        );
+        assert_eq!(rc_name(SQLITE_CANTOPEN_ISDIR as _), "SQLITE_CANTOPEN_ISDIR");
+        // Unknown extended and primary codes
+        assert_eq!(rc_name((SQLITE_IOERR | 99 << 8) as _), "SQLITE_IOERR_99");
+        assert_eq!(rc_name(50), "50");
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `vfsstat_files` table: statistics of every opened file path, with the
+//! `vfsstat_tag` it was last opened with.
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use crate::{
+    table::{Row, Rows, Table, Value},
+    vfs::Vfs,
+    StatField,
+};
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_files\0",
+    schema: b"CREATE TABLE x(path,filetype,stat,count,tag)\0",
+    rows: Rows::Scan(rows),
+};
+
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    let paths = vfs_.paths.lock().unwrap_or_else(|err| err.into_inner());
+    let mut rows = Vec::with_capacity(paths.len() * StatField::ALL.len());
+    for (path, path_stats) in paths.iter() {
+        let tag = path_stats
+            .tag
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+            .map_or(Value::Null, Value::from);
+        for field in StatField::ALL {
+            if field.any_only() {
+                continue;
+            }
+            rows.push(vec![
+                path.to_string().into(),
+                path_stats.filetype.name().into(),
+                field.name().into(),
+                path_stats.stats.get(field).into(),
+                tag.clone(),
+            ]);
+        }
+    }
+    rows
+}
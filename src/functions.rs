@@ -0,0 +1,349 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! SQL functions reading and resetting the `vtabstat` counters.
+//!
+//! They use the stat VFS of the main database of the calling connection, or
+//! the first registered stat VFS if it is opened with another VFS.
+
+use alloc::{format, string::String};
+use core::sync::atomic::Ordering;
+
+use crate::{
+    marks::Mark,
+    sqlite3ext::{
+        sqlite3, sqlite3_context, sqlite3_value, SQLITE_DIRECTONLY, SQLITE_INNOCUOUS, SQLITE_OK,
+        SQLITE_UTF8,
+    },
+    vfs::Vfs,
+    FileType, StatField,
+};
+
+/// Subtype of JSON text values, as used by the JSON1 functions.
+const JSON_SUBTYPE: ::core::ffi::c_uint = b'J' as _;
+
+/// `SQLITE_RESULT_SUBTYPE`, which SQLite 3.45 and later require from
+/// functions that set a subtype. Older versions ignore it.
+const SQLITE_RESULT_SUBTYPE: u32 = 0x0100_0000;
+
+type Function = unsafe extern "C" fn(
+    ctx: *mut sqlite3_context,
+    argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+);
+
+/// Name, number of arguments (`-1` for any), flags and implementation of
+/// every function.
+const FUNCTIONS: &[(&[u8], ::core::ffi::c_int, u32, Function)] = &[
+    (b"vfsstat_get\0", 2, SQLITE_INNOCUOUS, vfsstat_get),
+    (b"vfsstat_reset\0", -1, SQLITE_DIRECTONLY, vfsstat_reset),
+    (b"vfsstat_total\0", 1, SQLITE_INNOCUOUS, vfsstat_total),
+    (
+        b"vfsstat_since_reset\0",
+        0,
+        SQLITE_INNOCUOUS,
+        vfsstat_since_reset,
+    ),
+    (
+        b"vfsstat_json\0",
+        -1,
+        SQLITE_INNOCUOUS | SQLITE_RESULT_SUBTYPE,
+        vfsstat_json,
+    ),
+    (
+        b"vfsstat_prometheus\0",
+        0,
+        SQLITE_INNOCUOUS,
+        vfsstat_prometheus,
+    ),
+    (b"vfsstat_mark\0", 1, SQLITE_DIRECTONLY, vfsstat_mark),
+    (
+        b"vfsstat_drop_mark\0",
+        1,
+        SQLITE_DIRECTONLY,
+        vfsstat_drop_mark,
+    ),
+    (
+        b"vfsstat_register\0",
+        2,
+        SQLITE_DIRECTONLY,
+        vfsstat_register,
+    ),
+];
+
+/// Registers the SQL functions on `db`.
+///
+/// # Safety
+///
+/// `db` must be an open connection.
+pub unsafe fn register(db: *mut sqlite3) -> Result<(), String> {
+    for (name, n_arg, flags, func) in FUNCTIONS {
+        let ret = unsafe {
+            ((*crate::API).create_function_v2.unwrap())(
+                db,
+                name.as_ptr() as _,
+                *n_arg,
+                (SQLITE_UTF8 | flags) as _,
+                core::ptr::null_mut(),
+                Some(*func),
+                None,
+                None,
+                None,
+            )
+        };
+        if ret != SQLITE_OK as _ {
+            return Err(format!("Could not create_function_v2, returned {}", ret));
+        }
+    }
+    Ok(())
+}
+
+unsafe fn result_error(ctx: *mut sqlite3_context, msg: &str) {
+    unsafe {
+        ((*crate::API).result_error.unwrap())(ctx, msg.as_ptr() as _, msg.len() as _);
+    }
+}
+
+/// Returns the text of `value`, or `None` if it is NULL.
+unsafe fn value_text<'a>(value: *mut sqlite3_value) -> Option<&'a [u8]> {
+    let text = unsafe { ((*crate::API).value_text.unwrap())(value) };
+    if text.is_null() {
+        None
+    } else {
+        Some(unsafe { core::ffi::CStr::from_ptr(text as _) }.to_bytes())
+    }
+}
+
+unsafe fn filetype_arg(ctx: *mut sqlite3_context, value: *mut sqlite3_value) -> Option<FileType> {
+    let filetype = unsafe { value_text(value) }.and_then(FileType::from_name);
+    if filetype.is_none() {
+        unsafe { result_error(ctx, "unknown file type") };
+    }
+    filetype
+}
+
+unsafe fn field_arg(ctx: *mut sqlite3_context, value: *mut sqlite3_value) -> Option<StatField> {
+    let field = unsafe { value_text(value) }.and_then(StatField::from_name);
+    if field.is_none() {
+        unsafe { result_error(ctx, "unknown stat") };
+    }
+    field
+}
+
+/// Returns the stat VFS of the main database of the calling connection, or the
+/// one returned by [`Vfs::find`] if it is opened with another VFS.
+unsafe fn find_vfs(ctx: *mut sqlite3_context) -> Option<&'static Vfs> {
+    let db = unsafe { ((*crate::API).context_db_handle.unwrap())(ctx) };
+    let vfs_ = unsafe { Vfs::of_connection(db).or_else(Vfs::find) };
+    if vfs_.is_none() {
+        unsafe { result_error(ctx, "stat vfs is not registered") };
+    }
+    vfs_
+}
+
+/// `vfsstat_get(file, stat)`: the value of one counter.
+unsafe extern "C" fn vfsstat_get(
+    ctx: *mut sqlite3_context,
+    _argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    let argv = unsafe { core::slice::from_raw_parts(argv, 2) };
+    let (vfs_, filetype, field) = match unsafe {
+        (
+            find_vfs(ctx),
+            filetype_arg(ctx, argv[0]),
+            field_arg(ctx, argv[1]),
+        )
+    } {
+        (Some(vfs_), Some(filetype), Some(field)) => (vfs_, filetype, field),
+        _ => return,
+    };
+    let count = vfs_.file_stats.file(filetype).get(field);
+    unsafe { ((*crate::API).result_int64.unwrap())(ctx, count as i64) };
+}
+
+/// `vfsstat_reset([file[, stat]])`: zeroes the counters of `file` and `stat`.
+/// Without arguments, every statistic of the VFS is reset.
+unsafe extern "C" fn vfsstat_reset(
+    ctx: *mut sqlite3_context,
+    argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    if argc > 2 {
+        unsafe { result_error(ctx, "vfsstat_reset() takes at most 2 arguments") };
+        return;
+    }
+    let argv = if argc > 0 {
+        unsafe { core::slice::from_raw_parts(argv, argc as usize) }
+    } else {
+        &[]
+    };
+    let vfs_ = if let Some(vfs_) = unsafe { find_vfs(ctx) } {
+        vfs_
+    } else {
+        return;
+    };
+    match *argv {
+        [] => vfs_.reset(),
+        [file] => {
+            if let Some(filetype) = unsafe { filetype_arg(ctx, file) } {
+                vfs_.file_stats.file(filetype).reset();
+            }
+        }
+        [file, stat] => {
+            if let (Some(filetype), Some(field)) =
+                unsafe { (filetype_arg(ctx, file), field_arg(ctx, stat)) }
+            {
+                vfs_.file_stats
+                    .file(filetype)
+                    .field(field)
+                    .store(0, Ordering::Relaxed);
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// `vfsstat_total(stat)`: the sum of a counter over all file types.
+unsafe extern "C" fn vfsstat_total(
+    ctx: *mut sqlite3_context,
+    _argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    let (vfs_, field) = match unsafe { (find_vfs(ctx), field_arg(ctx, *argv)) } {
+        (Some(vfs_), Some(field)) => (vfs_, field),
+        _ => return,
+    };
+    let total = FileType::ALL
+        .iter()
+        .map(|filetype| vfs_.file_stats.file(*filetype).get(field))
+        .fold(0_u64, u64::wrapping_add);
+    unsafe { ((*crate::API).result_int64.unwrap())(ctx, total as i64) };
+}
+
+/// `vfsstat_since_reset()`: seconds since the statistics were last reset with
+/// `vfsstat_reset()`, or since the extension was loaded.
+unsafe extern "C" fn vfsstat_since_reset(
+    ctx: *mut sqlite3_context,
+    _argc: ::core::ffi::c_int,
+    _argv: *mut *mut sqlite3_value,
+) {
+    let vfs_ = if let Some(vfs_) = unsafe { find_vfs(ctx) } {
+        vfs_
+    } else {
+        return;
+    };
+    let (since, _) = *vfs_
+        .last_reset
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    unsafe { ((*crate::API).result_double.unwrap())(ctx, since.elapsed().as_secs_f64()) };
+}
+
+/// `vfsstat_json([scope])`: the counters of the `'process'` (the default) or
+/// `'connection'` scope as a JSON object, see [`json`](crate::json).
+unsafe extern "C" fn vfsstat_json(
+    ctx: *mut sqlite3_context,
+    argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    if argc > 1 {
+        unsafe { result_error(ctx, "vfsstat_json() takes at most 1 argument") };
+        return;
+    }
+    let vfs_ = if let Some(vfs_) = unsafe { find_vfs(ctx) } {
+        vfs_
+    } else {
+        return;
+    };
+    let scope = if argc == 1 {
+        unsafe { value_text(*argv) }
+    } else {
+        Some(&b"process"[..])
+    };
+    let connection = match scope {
+        Some(b"process") => None,
+        Some(b"connection") => {
+            let db = unsafe { ((*crate::API).context_db_handle.unwrap())(ctx) };
+            Some(vfs_.connections.get(db))
+        }
+        _ => {
+            unsafe { result_error(ctx, "scope must be 'process' or 'connection'") };
+            return;
+        }
+    };
+    let json = crate::json::to_json(vfs_, connection.as_deref());
+    unsafe {
+        crate::result_str(ctx, &json);
+        ((*crate::API).result_subtype.unwrap())(ctx, JSON_SUBTYPE);
+    }
+}
+
+/// `vfsstat_prometheus()`: the statistics in the Prometheus text format, see
+/// [`prometheus`](crate::prometheus).
+unsafe extern "C" fn vfsstat_prometheus(
+    ctx: *mut sqlite3_context,
+    _argc: ::core::ffi::c_int,
+    _argv: *mut *mut sqlite3_value,
+) {
+    if let Some(vfs_) = unsafe { find_vfs(ctx) } {
+        unsafe { crate::result_str(ctx, &crate::prometheus::to_prometheus(vfs_)) };
+    }
+}
+
+unsafe fn mark_name<'a>(ctx: *mut sqlite3_context, value: *mut sqlite3_value) -> Option<&'a str> {
+    let name = unsafe { value_text(value) }.and_then(|name| core::str::from_utf8(name).ok());
+    if name.is_none() {
+        unsafe { result_error(ctx, "mark name must be text") };
+    }
+    name
+}
+
+/// `vfsstat_mark(name)`: stores a copy of the process-wide counters as `name`,
+/// for use with the `vfsstat_delta` table.
+unsafe extern "C" fn vfsstat_mark(
+    ctx: *mut sqlite3_context,
+    _argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    if let (Some(vfs_), Some(name)) = unsafe { (find_vfs(ctx), mark_name(ctx, *argv)) } {
+        vfs_.marks.set(name, Mark::new(&vfs_.file_stats));
+    }
+}
+
+/// `vfsstat_drop_mark(name)`: forgets the mark `name`. Returns whether it
+/// existed.
+unsafe extern "C" fn vfsstat_drop_mark(
+    ctx: *mut sqlite3_context,
+    _argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    if let (Some(vfs_), Some(name)) = unsafe { (find_vfs(ctx), mark_name(ctx, *argv)) } {
+        let removed = vfs_.marks.remove(name);
+        unsafe { ((*crate::API).result_int.unwrap())(ctx, removed.into()) };
+    }
+}
+
+/// `vfsstat_register(shim_name, parent_name)`: registers a stat VFS named
+/// `shim_name` over the VFS `parent_name`, with its own statistics, or reuses
+/// the one already registered with these names.
+unsafe extern "C" fn vfsstat_register(
+    ctx: *mut sqlite3_context,
+    _argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    let argv = unsafe { core::slice::from_raw_parts(argv, 2) };
+    let names = unsafe { (value_text(argv[0]), value_text(argv[1])) };
+    let (name, parent) = match names {
+        (Some(name), Some(parent)) => (
+            String::from_utf8_lossy(name),
+            String::from_utf8_lossy(parent),
+        ),
+        _ => {
+            unsafe { result_error(ctx, "VFS names must be text") };
+            return;
+        }
+    };
+    if let Err(err) = unsafe { Vfs::register(&name, Some(&parent), false) } {
+        unsafe { result_error(ctx, &err) };
+    }
+}
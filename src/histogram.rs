@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Lock-free histogram with power-of-two buckets.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Number of buckets: one for zero and one for each bit length of a `u64`.
+pub const BUCKETS: usize = 65;
+
+/// Histogram with power-of-two buckets.
+///
+/// Bucket `0` holds the value `0` and bucket `i > 0` holds the values in
+/// `2^(i-1)..=2^i - 1`.
+#[derive(Debug)]
+pub struct Histogram {
+    buckets: [AtomicU64; BUCKETS],
+    /// Sum of the recorded values
+    sum: AtomicU64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: core::array::from_fn(|_| AtomicU64::new(0)),
+            sum: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Histogram {
+    /// Returns the bucket `value` falls in.
+    pub const fn bucket(value: u64) -> usize {
+        (u64::BITS - value.leading_zeros()) as usize
+    }
+
+    /// Returns the smallest and largest value of `bucket`.
+    pub const fn bounds(bucket: usize) -> (u64, u64) {
+        match bucket {
+            0 => (0, 0),
+            64 => (1 << 63, u64::MAX),
+            _ => (1 << (bucket - 1), (1 << bucket) - 1),
+        }
+    }
+
+    pub fn record(&self, value: u64) {
+        self.buckets[Self::bucket(value)].fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// Reads the current count of every bucket.
+    pub fn snapshot(&self) -> [u64; BUCKETS] {
+        core::array::from_fn(|i| self.buckets[i].load(Ordering::Relaxed))
+    }
+
+    /// Returns the sum of the recorded values, wrapping on overflow.
+    pub fn sum(&self) -> u64 {
+        self.sum.load(Ordering::Relaxed)
+    }
+
+    pub fn reset(&self) {
+        for bucket in &self.buckets {
+            bucket.store(0, Ordering::Relaxed);
+        }
+        self.sum.store(0, Ordering::Relaxed);
+    }
+
+    /// Estimates the `p`-th percentile (`0.0..=1.0`) of `counts` as the upper
+    /// bound of the bucket it falls in.
+    pub fn percentile(counts: &[u64; BUCKETS], p: f64) -> Option<u64> {
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let rank = ((total as f64 * p).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, count) in counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Some(Self::bounds(bucket).1);
+            }
+        }
+        Some(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buckets() {
+        assert_eq!(Histogram::bucket(0), 0);
+        assert_eq!(Histogram::bucket(1), 1);
+        assert_eq!(Histogram::bucket(2), 2);
+        assert_eq!(Histogram::bucket(3), 2);
+        assert_eq!(Histogram::bucket(4), 3);
+        assert_eq!(Histogram::bucket(u64::MAX), BUCKETS - 1);
+        for value in [0, 1, 5, 4096, 4097, 1 << 40, u64::MAX] {
+            let (lo, hi) = Histogram::bounds(Histogram::bucket(value));
+            assert!(
+                lo <= value && value <= hi,
+                "{} not in {}..={}",
+                value,
+                lo,
+                hi
+            );
+        }
+        assert_eq!(Histogram::bounds(3), (4, 7));
+        assert_eq!(Histogram::bounds(64), (1 << 63, u64::MAX));
+    }
+
+    #[test]
+    fn test_percentile() {
+        let histogram = Histogram::default();
+        assert_eq!(Histogram::percentile(&histogram.snapshot(), 0.5), None);
+        // 90 values in bucket 4 (8..=15) and 10 in bucket 11 (1024..=2047)
+        for _ in 0..90 {
+            histogram.record(10);
+        }
+        for _ in 0..10 {
+            histogram.record(1500);
+        }
+        assert_eq!(histogram.sum(), 90 * 10 + 10 * 1500);
+        let counts = histogram.snapshot();
+        assert_eq!(Histogram::percentile(&counts, 0.0), Some(15));
+        assert_eq!(Histogram::percentile(&counts, 0.5), Some(15));
+        assert_eq!(Histogram::percentile(&counts, 0.9), Some(15));
+        assert_eq!(Histogram::percentile(&counts, 0.91), Some(2047));
+        assert_eq!(Histogram::percentile(&counts, 1.0), Some(2047));
+        histogram.reset();
+        assert_eq!(histogram.sum(), 0);
+        assert_eq!(Histogram::percentile(&histogram.snapshot(), 0.5), None);
+    }
+}
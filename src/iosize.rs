@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Distribution of the request sizes of reads, writes and fetches, and the
+//! `vfsstat_iosize` table.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    histogram::Histogram,
+    table::{Row, Rows, Table},
+    vfs::Vfs,
+    FileType, IoOp,
+};
+
+/// I/O methods whose request sizes are recorded.
+pub const OPS: [IoOp; 3] = [IoOp::Read, IoOp::Write, IoOp::Fetch];
+
+/// Histograms of the `iAmt` argument of [`OPS`] for every file type.
+#[derive(Debug)]
+pub struct IoSizes {
+    histograms: [[Histogram; OPS.len()]; FileType::ALL.len()],
+}
+
+impl Default for IoSizes {
+    fn default() -> Self {
+        Self {
+            histograms: core::array::from_fn(|_| core::array::from_fn(|_| Histogram::default())),
+        }
+    }
+}
+
+impl IoSizes {
+    /// Returns the histogram of `op`, or `None` if its sizes are not recorded.
+    pub fn get(&self, filetype: FileType, op: IoOp) -> Option<&Histogram> {
+        let idx = OPS.iter().position(|o| *o == op)?;
+        Some(&self.histograms[filetype as usize][idx])
+    }
+
+    pub fn reset(&self) {
+        for histogram in self.histograms.iter().flatten() {
+            histogram.reset();
+        }
+    }
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_iosize\0",
+    schema: b"CREATE TABLE x(file,op,bucket_lo,bucket_hi,count)\0",
+    rows: Rows::Scan(rows),
+};
+
+/// One row per non-empty bucket.
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    let mut rows = Vec::new();
+    for filetype in FileType::ALL {
+        for op in OPS {
+            let counts = vfs_.iosize.get(filetype, op).unwrap().snapshot();
+            for (bucket, count) in counts.iter().enumerate() {
+                if *count == 0 {
+                    continue;
+                }
+                let (lo, hi) = Histogram::bounds(bucket);
+                rows.push(vec![
+                    filetype.name().into(),
+                    op.name().into(),
+                    lo.into(),
+                    hi.into(),
+                    (*count).into(),
+                ]);
+            }
+        }
+    }
+    rows
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! JSON snapshots of the `vtabstat` counters, returned by `vfsstat_json()`.
+//!
+//! The schema is versioned by [`VERSION`]; fields are only added to a version,
+//! never renamed or removed:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "vfs": "vfsstat_rs",
+//!   "parent": "unix",
+//!   "scope": "process",
+//!   "reset_at": 1700000000.123,
+//!   "since_reset": 12.5,
+//!   "files": {
+//!     "main": {"bytesIn": 4096, "bytesOut": 0, "read": 1, ...},
+//!     "journal": {...},
+//!     ...
+//!     "*": {...}
+//!   }
+//! }
+//! ```
+//!
+//! `reset_at` is in seconds since the Unix epoch and `since_reset` in seconds.
+//! `files` has one object per file type and one member per stat, in the order
+//! of the `vtabstat` table.
+
+use alloc::string::String;
+use core::fmt::Write;
+use std::time::UNIX_EPOCH;
+
+use crate::{vfs::Vfs, FileStats, FileType, StatField};
+
+/// Version of the JSON schema.
+pub const VERSION: u64 = 1;
+
+/// Serializes the counters of `vfs_`, or those of a connection if
+/// `connection` is given.
+pub fn to_json(vfs_: &Vfs, connection: Option<&FileStats>) -> String {
+    let (since, reset_at) = *vfs_
+        .last_reset
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    let reset_at = reset_at
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_secs_f64());
+    let file_stats = connection.unwrap_or(&vfs_.file_stats);
+
+    let mut out = String::new();
+    out.push_str("{\"version\":");
+    let _ = write!(out, "{}", VERSION);
+    out.push_str(",\"vfs\":");
+    push_str(&mut out, vfs_.name());
+    out.push_str(",\"parent\":");
+    push_str(&mut out, vfs_.parent_name());
+    out.push_str(",\"scope\":");
+    push_str(
+        &mut out,
+        if connection.is_some() {
+            "connection"
+        } else {
+            "process"
+        },
+    );
+    let _ = write!(
+        out,
+        ",\"reset_at\":{:?},\"since_reset\":{:?},\"files\":{{",
+        reset_at,
+        since.elapsed().as_secs_f64()
+    );
+    for (i, filetype) in FileType::ALL.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        push_str(&mut out, filetype.name());
+        out.push_str(":{");
+        let stats = file_stats.file(*filetype);
+        for (j, field) in StatField::ALL.iter().enumerate() {
+            if j > 0 {
+                out.push(',');
+            }
+            push_str(&mut out, field.name());
+            let _ = write!(out, ":{}", stats.get(*field));
+        }
+        out.push('}');
+    }
+    out.push_str("}}");
+    out
+}
+
+/// Appends `s` as a JSON string.
+fn push_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_str() {
+        let escape = |s: &str| {
+            let mut out = String::new();
+            push_str(&mut out, s);
+            out
+        };
+        assert_eq!(escape("main"), r#""main""#);
+        assert_eq!(escape(r#"a "b" \ c"#), r#""a \"b\" \\ c""#);
+        assert_eq!(escape("\n\r\t"), r#""\n\r\t""#);
+        assert_eq!(escape("\u{0}\u{1f}"), r#""\u0000\u001f""#);
+        assert_eq!(escape("é/€"), "\"é/€\"");
+    }
+}
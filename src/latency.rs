@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Time spent in the parent VFS by every I/O method, and the
+//! `vfsstat_latency` table.
+
+use alloc::{vec, vec::Vec};
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::{
+    histogram::Histogram,
+    table::{Row, Rows, Table, Value},
+    vfs::Vfs,
+    FileType, IoOp,
+};
+
+/// Latency of one I/O method, in nanoseconds.
+#[derive(Debug)]
+pub struct LatencyStats {
+    /// Number of calls
+    pub count: AtomicU64,
+    /// Total time of all calls
+    pub total_ns: AtomicU64,
+    /// Fastest call, `u64::MAX` if there were no calls
+    pub min_ns: AtomicU64,
+    /// Slowest call
+    pub max_ns: AtomicU64,
+    pub histogram: Histogram,
+}
+
+impl Default for LatencyStats {
+    fn default() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            total_ns: AtomicU64::new(0),
+            min_ns: AtomicU64::new(u64::MAX),
+            max_ns: AtomicU64::new(0),
+            histogram: Histogram::default(),
+        }
+    }
+}
+
+impl LatencyStats {
+    pub fn record(&self, elapsed: Duration) {
+        let ns = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.total_ns.fetch_add(ns, Ordering::Relaxed);
+        self.min_ns.fetch_min(ns, Ordering::Relaxed);
+        self.max_ns.fetch_max(ns, Ordering::Relaxed);
+        self.histogram.record(ns);
+    }
+
+    pub fn reset(&self) {
+        self.count.store(0, Ordering::Relaxed);
+        self.total_ns.store(0, Ordering::Relaxed);
+        self.min_ns.store(u64::MAX, Ordering::Relaxed);
+        self.max_ns.store(0, Ordering::Relaxed);
+        self.histogram.reset();
+    }
+}
+
+/// [`LatencyStats`] of every I/O method of every file type.
+#[derive(Debug)]
+pub struct Latencies {
+    stats: [[LatencyStats; IoOp::ALL.len()]; FileType::ALL.len()],
+}
+
+impl Default for Latencies {
+    fn default() -> Self {
+        Self {
+            stats: core::array::from_fn(|_| core::array::from_fn(|_| LatencyStats::default())),
+        }
+    }
+}
+
+impl Latencies {
+    pub fn get(&self, filetype: FileType, op: IoOp) -> &LatencyStats {
+        &self.stats[filetype as usize][op as usize]
+    }
+
+    pub fn reset(&self) {
+        for stats in self.stats.iter().flatten() {
+            stats.reset();
+        }
+    }
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_latency\0",
+    schema: b"CREATE TABLE x(file,op,count,total_ns,min_ns,p50_ns,p90_ns,p99_ns,max_ns)\0",
+    rows: Rows::Scan(rows),
+};
+
+/// One row per file type and method that has been called at least once.
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    let mut rows = Vec::new();
+    for filetype in FileType::ALL {
+        for op in IoOp::ALL {
+            let stats = vfs_.latency.get(filetype, op);
+            let count = stats.count.load(Ordering::Relaxed);
+            if count == 0 {
+                continue;
+            }
+            let max_ns = stats.max_ns.load(Ordering::Relaxed);
+            let counts = stats.histogram.snapshot();
+            let percentile = |p| {
+                Histogram::percentile(&counts, p).map_or(Value::Null, |ns| ns.min(max_ns).into())
+            };
+            rows.push(vec![
+                filetype.name().into(),
+                op.name().into(),
+                count.into(),
+                stats.total_ns.load(Ordering::Relaxed).into(),
+                stats.min_ns.load(Ordering::Relaxed).into(),
+                percentile(0.5),
+                percentile(0.9),
+                percentile(0.99),
+                max_ns.into(),
+            ]);
+        }
+    }
+    rows
+}
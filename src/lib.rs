@@ -16,16 +16,41 @@ extern crate alloc;
 #[allow(clippy::type_complexity)]
 pub(crate) mod sqlite3ext;
 
-use alloc::{ffi::CString, string::String};
-use core::convert::TryInto;
+use alloc::{ffi::CString, string::String, sync::Arc};
+use core::{
+    convert::TryInto,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 use log::{debug, trace};
 use sqlite3ext::{
-    sqlite3, sqlite3_api_routines, SQLITE_ERROR, SQLITE_OK, SQLITE_OK_LOAD_PERMANENTLY,
+    sqlite3, sqlite3_api_routines, sqlite3_context, sqlite3_destructor_type, SQLITE_ERROR,
+    SQLITE_OK, SQLITE_OK_LOAD_PERMANENTLY, SQLITE_UTF8,
 };
 
+pub mod btree;
+pub mod commits;
+pub mod config;
+pub mod connections;
+pub mod errors;
+pub mod files;
+pub mod functions;
+pub mod histogram;
+pub mod iosize;
+pub mod json;
+pub mod latency;
+pub mod locks;
+pub mod marks;
+pub mod pages;
+pub mod pragmas;
+pub mod prometheus;
+pub mod shm;
+pub mod statements;
+pub mod table;
+pub mod tags;
 pub mod vfs;
 pub mod vtab;
+pub mod wide;
 
 static mut API: *mut sqlite3_api_routines = core::ptr::null_mut();
 
@@ -92,7 +117,7 @@ static mut API: *mut sqlite3_api_routines = core::ptr::null_mut();
 
 /// File types
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FileType {
     /// Main database file
     Main = 0,
@@ -114,40 +139,154 @@ pub enum FileType {
     Any = 8,
 }
 
+impl FileType {
+    /// All file types, in `vtabstat` order.
+    pub const ALL: [FileType; 9] = [
+        FileType::Main,
+        FileType::Journal,
+        FileType::Wal,
+        FileType::MasterJournal,
+        FileType::SubJournal,
+        FileType::TempDb,
+        FileType::TempJournal,
+        FileType::Transient,
+        FileType::Any,
+    ];
+
+    /// Name used for this file type in the stats tables.
+    pub const fn name(self) -> &'static str {
+        match self {
+            FileType::Main => "main",
+            FileType::Journal => "journal",
+            FileType::Wal => "wal",
+            FileType::MasterJournal => "master-journal",
+            FileType::SubJournal => "sub-journal",
+            FileType::TempDb => "temp-database",
+            FileType::TempJournal => "temp-journal",
+            FileType::Transient => "transient-db",
+            FileType::Any => "*",
+        }
+    }
+
+    /// Looks up a file type by its [`name`](Self::name).
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|filetype| filetype.name().as_bytes() == name)
+    }
+}
+
 /// Stat types
+///
+/// Every counter is an [`AtomicU64`] so that the shims can bump them through a
+/// shared reference from any thread; SQLite may call into the same `Vfs` from
+/// many connections concurrently in multi-threaded and serialized modes.
 #[repr(C)]
 #[derive(Debug, Default)]
 pub struct Stats {
     /// 0,   Bytes read in
-    BytesIn: u64,
+    BytesIn: AtomicU64,
     /// 1,   Bytes written out
-    BytesOut: u64,
+    BytesOut: AtomicU64,
     /// 2,   Read requests
-    Read: u64,
+    Read: AtomicU64,
     /// 3,   Write requests
-    Write: u64,
+    Write: AtomicU64,
     /// 4,   Syncs
-    Sync: u64,
+    Sync: AtomicU64,
     /// 5,   File opens
-    Open: u64,
+    Open: AtomicU64,
     /// 6,   Lock requests
-    Lock: u64,
+    Lock: AtomicU64,
     /// 7,   xAccess calls.  filetype==ANY only
-    Access: u64,
+    Access: AtomicU64,
     /// 8,   xDelete calls.  filetype==ANY only
-    Delete: u64,
+    Delete: AtomicU64,
     /// 9,   xFullPathname calls.  ANY only
-    FullPath: u64,
+    FullPath: AtomicU64,
     /// 10,   xRandomness calls.    ANY only
-    Random: u64,
+    Random: AtomicU64,
     /// 11,   xSleep calls.         ANY only
-    Sleep: u64,
+    Sleep: AtomicU64,
     /// 12,   xCurrentTime calls.   ANY only
-    CurrentTime: u64,
+    CurrentTime: AtomicU64,
+    /// 13,   xTruncate calls
+    Truncate: AtomicU64,
+    /// 14,   xFileSize calls
+    FileSize: AtomicU64,
+    /// 15,   xFileControl calls
+    FileControl: AtomicU64,
+    /// 16,   xClose calls
+    Close: AtomicU64,
+    /// 17,   xUnlock calls
+    Unlock: AtomicU64,
+    /// 18,   xCheckReservedLock calls
+    CheckReservedLock: AtomicU64,
+    /// 19,   xShmMap calls
+    ShmMap: AtomicU64,
+    /// 20,   xShmLock calls
+    ShmLock: AtomicU64,
+    /// 21,   xShmBarrier calls
+    ShmBarrier: AtomicU64,
+    /// 22,   xShmUnmap calls
+    ShmUnmap: AtomicU64,
+    /// 23,   xFetch calls
+    Fetch: AtomicU64,
+    /// 24,   xUnfetch calls
+    Unfetch: AtomicU64,
+    /// 25,   Bytes memory-mapped by xFetch
+    BytesFetched: AtomicU64,
+}
+
+impl Stats {
+    /// Returns the counter of `field`.
+    pub fn field(&self, field: StatField) -> &AtomicU64 {
+        match field {
+            StatField::BytesIn => &self.BytesIn,
+            StatField::BytesOut => &self.BytesOut,
+            StatField::Read => &self.Read,
+            StatField::Write => &self.Write,
+            StatField::Sync => &self.Sync,
+            StatField::Open => &self.Open,
+            StatField::Lock => &self.Lock,
+            StatField::Access => &self.Access,
+            StatField::Delete => &self.Delete,
+            StatField::FullPath => &self.FullPath,
+            StatField::Random => &self.Random,
+            StatField::Sleep => &self.Sleep,
+            StatField::CurrentTime => &self.CurrentTime,
+            StatField::Truncate => &self.Truncate,
+            StatField::FileSize => &self.FileSize,
+            StatField::FileControl => &self.FileControl,
+            StatField::Close => &self.Close,
+            StatField::Unlock => &self.Unlock,
+            StatField::CheckReservedLock => &self.CheckReservedLock,
+            StatField::ShmMap => &self.ShmMap,
+            StatField::ShmLock => &self.ShmLock,
+            StatField::ShmBarrier => &self.ShmBarrier,
+            StatField::ShmUnmap => &self.ShmUnmap,
+            StatField::Fetch => &self.Fetch,
+            StatField::Unfetch => &self.Unfetch,
+            StatField::BytesFetched => &self.BytesFetched,
+        }
+    }
+
+    /// Reads the current value of `field`.
+    pub fn get(&self, field: StatField) -> u64 {
+        self.field(field).load(Ordering::Relaxed)
+    }
+
+    /// Zeroes every counter.
+    pub fn reset(&self) {
+        for field in StatField::ALL {
+            self.field(field).store(0, Ordering::Relaxed);
+        }
+    }
 }
 
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum StatField {
     /// Bytes read in
     BytesIn = 0,
@@ -175,6 +314,207 @@ pub enum StatField {
     Sleep = 11,
     /// xCurrentTime calls.   ANY only
     CurrentTime = 12,
+    /// xTruncate calls
+    Truncate = 13,
+    /// xFileSize calls
+    FileSize = 14,
+    /// xFileControl calls
+    FileControl = 15,
+    /// xClose calls
+    Close = 16,
+    /// xUnlock calls
+    Unlock = 17,
+    /// xCheckReservedLock calls
+    CheckReservedLock = 18,
+    /// xShmMap calls
+    ShmMap = 19,
+    /// xShmLock calls
+    ShmLock = 20,
+    /// xShmBarrier calls
+    ShmBarrier = 21,
+    /// xShmUnmap calls
+    ShmUnmap = 22,
+    /// xFetch calls
+    Fetch = 23,
+    /// xUnfetch calls
+    Unfetch = 24,
+    /// Bytes memory-mapped by xFetch
+    BytesFetched = 25,
+}
+
+impl StatField {
+    /// All stat fields, in `vtabstat` order.
+    pub const ALL: [StatField; 26] = [
+        StatField::BytesIn,
+        StatField::BytesOut,
+        StatField::Read,
+        StatField::Write,
+        StatField::Sync,
+        StatField::Open,
+        StatField::Lock,
+        StatField::Access,
+        StatField::Delete,
+        StatField::FullPath,
+        StatField::Random,
+        StatField::Sleep,
+        StatField::CurrentTime,
+        StatField::Truncate,
+        StatField::FileSize,
+        StatField::FileControl,
+        StatField::Close,
+        StatField::Unlock,
+        StatField::CheckReservedLock,
+        StatField::ShmMap,
+        StatField::ShmLock,
+        StatField::ShmBarrier,
+        StatField::ShmUnmap,
+        StatField::Fetch,
+        StatField::Unfetch,
+        StatField::BytesFetched,
+    ];
+
+    /// Name used for this field in the stats tables.
+    pub const fn name(self) -> &'static str {
+        match self {
+            StatField::BytesIn => "bytesIn",
+            StatField::BytesOut => "bytesOut",
+            StatField::Read => "read",
+            StatField::Write => "write",
+            StatField::Sync => "sync",
+            StatField::Open => "open",
+            StatField::Lock => "lock",
+            StatField::Access => "access",
+            StatField::Delete => "delete",
+            StatField::FullPath => "fullPath",
+            StatField::Random => "random",
+            StatField::Sleep => "sleep",
+            StatField::CurrentTime => "currentTime",
+            StatField::Truncate => "truncate",
+            StatField::FileSize => "fileSize",
+            StatField::FileControl => "fileControl",
+            StatField::Close => "close",
+            StatField::Unlock => "unlock",
+            StatField::CheckReservedLock => "checkReservedLock",
+            StatField::ShmMap => "shmMap",
+            StatField::ShmLock => "shmLock",
+            StatField::ShmBarrier => "shmBarrier",
+            StatField::ShmUnmap => "shmUnmap",
+            StatField::Fetch => "fetch",
+            StatField::Unfetch => "unfetch",
+            StatField::BytesFetched => "bytesFetched",
+        }
+    }
+
+    /// Whether this field is only ever counted for [`FileType::Any`], i.e. it
+    /// counts `sqlite3_vfs` methods that are not tied to an open file.
+    pub const fn any_only(self) -> bool {
+        matches!(
+            self,
+            StatField::Access
+                | StatField::Delete
+                | StatField::FullPath
+                | StatField::Random
+                | StatField::Sleep
+                | StatField::CurrentTime
+        )
+    }
+
+    /// Looks up a field by its [`name`](Self::name).
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|field| field.name().as_bytes() == name)
+    }
+}
+
+/// Methods of an open file, i.e. of `sqlite3_io_methods`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IoOp {
+    /// xClose
+    Close = 0,
+    /// xRead
+    Read = 1,
+    /// xWrite
+    Write = 2,
+    /// xTruncate
+    Truncate = 3,
+    /// xSync
+    Sync = 4,
+    /// xFileSize
+    FileSize = 5,
+    /// xLock
+    Lock = 6,
+    /// xUnlock
+    Unlock = 7,
+    /// xCheckReservedLock
+    CheckReservedLock = 8,
+    /// xFileControl
+    FileControl = 9,
+    /// xSectorSize
+    SectorSize = 10,
+    /// xDeviceCharacteristics
+    DeviceCharacteristics = 11,
+    /// xShmMap
+    ShmMap = 12,
+    /// xShmLock
+    ShmLock = 13,
+    /// xShmBarrier
+    ShmBarrier = 14,
+    /// xShmUnmap
+    ShmUnmap = 15,
+    /// xFetch
+    Fetch = 16,
+    /// xUnfetch
+    Unfetch = 17,
+}
+
+impl IoOp {
+    /// All I/O methods, in `sqlite3_io_methods` order.
+    pub const ALL: [IoOp; 18] = [
+        IoOp::Close,
+        IoOp::Read,
+        IoOp::Write,
+        IoOp::Truncate,
+        IoOp::Sync,
+        IoOp::FileSize,
+        IoOp::Lock,
+        IoOp::Unlock,
+        IoOp::CheckReservedLock,
+        IoOp::FileControl,
+        IoOp::SectorSize,
+        IoOp::DeviceCharacteristics,
+        IoOp::ShmMap,
+        IoOp::ShmLock,
+        IoOp::ShmBarrier,
+        IoOp::ShmUnmap,
+        IoOp::Fetch,
+        IoOp::Unfetch,
+    ];
+
+    /// Name used for this method in the stats tables.
+    pub const fn name(self) -> &'static str {
+        match self {
+            IoOp::Close => "close",
+            IoOp::Read => "read",
+            IoOp::Write => "write",
+            IoOp::Truncate => "truncate",
+            IoOp::Sync => "sync",
+            IoOp::FileSize => "fileSize",
+            IoOp::Lock => "lock",
+            IoOp::Unlock => "unlock",
+            IoOp::CheckReservedLock => "checkReservedLock",
+            IoOp::FileControl => "fileControl",
+            IoOp::SectorSize => "sectorSize",
+            IoOp::DeviceCharacteristics => "deviceCharacteristics",
+            IoOp::ShmMap => "shmMap",
+            IoOp::ShmLock => "shmLock",
+            IoOp::ShmBarrier => "shmBarrier",
+            IoOp::ShmUnmap => "shmUnmap",
+            IoOp::Fetch => "fetch",
+            IoOp::Unfetch => "unfetch",
+        }
+    }
 }
 
 #[repr(C)]
@@ -200,37 +540,102 @@ pub struct FileStats {
     any: Stats,
 }
 
+impl FileStats {
+    /// Returns the counters of `filetype`.
+    pub fn file(&self, filetype: FileType) -> &Stats {
+        match filetype {
+            FileType::Main => &self.main,
+            FileType::Journal => &self.journal,
+            FileType::Wal => &self.wal,
+            FileType::MasterJournal => &self.master_journal,
+            FileType::SubJournal => &self.sub_journal,
+            FileType::TempDb => &self.temp_db,
+            FileType::TempJournal => &self.temp_journal,
+            FileType::Transient => &self.transient,
+            FileType::Any => &self.any,
+        }
+    }
+
+    /// Zeroes the counters of every file type.
+    pub fn reset(&self) {
+        for filetype in FileType::ALL {
+            self.file(filetype).reset();
+        }
+    }
+}
+
+/// Statistics of a single opened file path.
+#[derive(Debug)]
+pub struct PathStats {
+    pub path: String,
+    /// File type the path was first opened as
+    pub filetype: FileType,
+    pub stats: Stats,
+    pub locks: locks::LockStats,
+    /// Page heatmap, for main and temp databases
+    pub pages: std::sync::Mutex<pages::PageMap>,
+    /// Page heatmaps of the open databases without a name, which share this
+    /// path but not their pages
+    pub unnamed_pages: std::sync::Mutex<Vec<Arc<std::sync::Mutex<pages::PageMap>>>>,
+    /// Owners of the pages, for main databases queried by `vfsstat_btree_io`
+    pub owners: btree::OwnersCache,
+    /// Write transaction in progress, for main databases
+    pub commit: commits::PendingCommit,
+    /// Last `vfsstat_tag` URI parameter the path was opened with
+    pub tag: std::sync::Mutex<Option<String>>,
+}
+
+impl PathStats {
+    pub fn new(path: String, filetype: FileType) -> Self {
+        Self {
+            path,
+            filetype,
+            stats: Stats::default(),
+            locks: locks::LockStats::default(),
+            pages: std::sync::Mutex::default(),
+            unnamed_pages: std::sync::Mutex::default(),
+            owners: btree::OwnersCache::default(),
+            commit: commits::PendingCommit::default(),
+            tag: std::sync::Mutex::default(),
+        }
+    }
+}
+
+/// Access a counter of a [`FileStats`].
+///
+/// `statcnt!(file_stats, filetype, Read)` evaluates to the `&AtomicU64` of the
+/// counter, and `statcnt!(file_stats, filetype, Read += n)` atomically adds `n`
+/// to it.
 #[macro_export]
 macro_rules! statcnt {
-    (mut $filestats:expr, $filetype:expr, $field:ident) => {{
-        match $filetype {
-            FileType::Main => &mut $filestats.main.$field,
-            FileType::Journal => &mut $filestats.journal.$field,
-            FileType::Wal => &mut $filestats.wal.$field,
-            FileType::MasterJournal => &mut $filestats.master_journal.$field,
-            FileType::SubJournal => &mut $filestats.sub_journal.$field,
-            FileType::TempDb => &mut $filestats.temp_db.$field,
-            FileType::TempJournal => &mut $filestats.temp_journal.$field,
-            FileType::Transient => &mut $filestats.transient.$field,
-            FileType::Any => &mut $filestats.any.$field,
-        }
+    ($filestats:expr, $filetype:expr, $field:ident += $n:expr) => {{
+        statcnt!($filestats, $filetype, $field)
+            .fetch_add($n as u64, ::core::sync::atomic::Ordering::Relaxed)
     }};
     ($filestats:expr, $filetype:expr, $field:ident) => {{
-        match $filetype {
-            FileType::Main => &$filestats.main.$field,
-            FileType::Journal => &$filestats.journal.$field,
-            FileType::Wal => &$filestats.wal.$field,
-            FileType::MasterJournal => &$filestats.master_journal.$field,
-            FileType::SubJournal => &$filestats.sub_journal.$field,
-            FileType::TempDb => &$filestats.temp_db.$field,
-            FileType::TempJournal => &$filestats.temp_journal.$field,
-            FileType::Transient => &$filestats.transient.$field,
-            FileType::Any => &$filestats.any.$field,
-        }
+        &$filestats.file($filetype).$field
     }};
 }
 
-fn err_to_sqlite3_str(err: String) -> Option<*mut ::core::ffi::c_char> {
+/// Sets the result of `ctx` to a copy of `s`.
+unsafe fn result_str(ctx: *mut sqlite3_context, s: &str) {
+    ((*API).result_text64.unwrap())(
+        ctx,
+        s.as_ptr() as _,
+        s.len() as _,
+        SQLITE_TRANSIENT(),
+        SQLITE_UTF8 as _,
+    )
+}
+
+/// The `SQLITE_TRANSIENT` destructor: SQLite makes its own copy of the value.
+pub(crate) fn SQLITE_TRANSIENT() -> sqlite3_destructor_type {
+    Some(unsafe {
+        core::mem::transmute::<isize, unsafe extern "C" fn(*mut ::core::ffi::c_void)>(-1)
+    })
+}
+
+pub(crate) fn err_to_sqlite3_str(err: String) -> Option<*mut ::core::ffi::c_char> {
     let err_s = CString::new(err).ok()?;
     let len = err_s.as_bytes_with_nul().len();
     let ptr: *mut ::core::ffi::c_char =
@@ -244,13 +649,43 @@ fn err_to_sqlite3_str(err: String) -> Option<*mut ::core::ffi::c_char> {
     }
 }
 
+/// Statistics tables registered alongside `vtabstat`.
+static TABLES: &[&table::Table] = &[
+    &files::TABLE,
+    &latency::TABLE,
+    &iosize::TABLE,
+    &errors::TABLE,
+    &locks::TABLE,
+    &shm::TABLE,
+    &pages::TABLE,
+    &btree::TABLE,
+    &statements::TABLE,
+    &commits::TABLE,
+    &commits::HISTOGRAMS_TABLE,
+    &wide::TABLE,
+    &marks::TABLE,
+    &marks::DELTA_TABLE,
+    &vfs::INSTANCES_TABLE,
+    &tags::TABLE,
+];
+
+/// Sets up every new connection, as an auto-extension.
+///
+/// # Safety
+///
+/// Only SQLite may call this, with an open connection `db` and a `pzErrMsg`
+/// valid for writes.
 #[no_mangle]
 pub unsafe extern "C" fn vtab_register(
     db: *mut sqlite3,
     pzErrMsg: *mut *mut ::core::ffi::c_char,
     _pApi: *mut sqlite3_api_routines,
 ) -> ::core::ffi::c_int {
-    if let Err(err) = vtab::VTab::create(db) {
+    if let Err(err) = vtab::VTab::create(db)
+        .and_then(|()| TABLES.iter().try_for_each(|t| t.create(db)))
+        .and_then(|()| statements::register(db))
+        .and_then(|()| functions::register(db))
+    {
         debug!("vtab::new() returned: {}", &err);
         if let Some(ptr) = err_to_sqlite3_str(err) {
             *pzErrMsg = ptr;
@@ -260,6 +695,13 @@ pub unsafe extern "C" fn vtab_register(
     SQLITE_OK as _
 }
 
+/// Loads the extension, registering the `vfsstat_rs` VFS as the default VFS
+/// unless the `VFSSTAT_DEFAULT` environment variable is `0`.
+///
+/// # Safety
+///
+/// Only SQLite may call this, as the entry point of the extension, with the API
+/// routines in `pApi`.
 #[no_mangle]
 pub unsafe extern "C" fn sqlite3_vfsstatrs_init(
     db: *mut sqlite3,
@@ -267,19 +709,49 @@ pub unsafe extern "C" fn sqlite3_vfsstatrs_init(
     pApi: *mut sqlite3_api_routines,
 ) -> ::core::ffi::c_int {
     trace!("sqlite3_vfsstat_rs_init");
+    let make_default = match std::env::var("VFSSTAT_DEFAULT").as_deref() {
+        Ok("0") => false,
+        Ok("1") | Err(_) => true,
+        Ok(value) => {
+            debug!("Ignoring VFSSTAT_DEFAULT: invalid value {:?}", value);
+            true
+        }
+    };
+    init(db, pzErrMsg, pApi, make_default)
+}
+
+/// Loads the extension without making `vfsstat_rs` the default VFS, so that
+/// only the databases opened with it, for example with a
+/// `file:test.db?vfs=vfsstat_rs` URI, are measured.
+///
+/// # Safety
+///
+/// Same as [`sqlite3_vfsstatrs_init`].
+#[no_mangle]
+pub unsafe extern "C" fn sqlite3_vfsstatrs_nodefault_init(
+    db: *mut sqlite3,
+    pzErrMsg: *mut *mut ::core::ffi::c_char,
+    pApi: *mut sqlite3_api_routines,
+) -> ::core::ffi::c_int {
+    trace!("sqlite3_vfsstatrs_nodefault_init");
+    init(db, pzErrMsg, pApi, false)
+}
+
+unsafe fn init(
+    db: *mut sqlite3,
+    pzErrMsg: *mut *mut ::core::ffi::c_char,
+    pApi: *mut sqlite3_api_routines,
+    make_default: bool,
+) -> ::core::ffi::c_int {
     API = pApi;
 
-    let vfs = match vfs::Vfs::new() {
-        Err(err) => {
-            debug!("vfs::new() returned: {}", &err);
-            if let Some(ptr) = err_to_sqlite3_str(err) {
-                *pzErrMsg = ptr;
-            }
-            return SQLITE_ERROR as _;
+    if let Err(err) = vfs::Vfs::register(vfs::VFS_NAME, None, make_default) {
+        debug!("vfs::new() returned: {}", &err);
+        if let Some(ptr) = err_to_sqlite3_str(err) {
+            *pzErrMsg = ptr;
         }
-        Ok(v) => v,
-    };
-    core::mem::forget(vfs);
+        return SQLITE_ERROR as _;
+    }
     let ret = vtab_register(db, pzErrMsg, pApi);
     if ret != SQLITE_OK as _ {
         return ret;
@@ -295,3 +767,47 @@ pub unsafe extern "C" fn sqlite3_vfsstatrs_init(
 
     SQLITE_OK_LOAD_PERMANENTLY as _
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concurrent_counters_are_exact() {
+        const THREADS: u64 = 8;
+        const ITERATIONS: u64 = 100_000;
+
+        let file_stats = FileStats::default();
+        std::thread::scope(|s| {
+            for _ in 0..THREADS {
+                s.spawn(|| {
+                    for i in 0..ITERATIONS {
+                        statcnt!(file_stats, FileType::Main, Read += 1);
+                        statcnt!(file_stats, FileType::Main, BytesIn += i % 4096);
+                        statcnt!(file_stats, FileType::Wal, Write += 1);
+                        statcnt!(file_stats, FileType::Any, Access += 1);
+                    }
+                });
+            }
+        });
+
+        let bytes_per_thread: u64 = (0..ITERATIONS).map(|i| i % 4096).sum();
+        assert_eq!(
+            file_stats.file(FileType::Main).get(StatField::Read),
+            THREADS * ITERATIONS
+        );
+        assert_eq!(
+            file_stats.file(FileType::Main).get(StatField::BytesIn),
+            THREADS * bytes_per_thread
+        );
+        assert_eq!(
+            file_stats.file(FileType::Wal).get(StatField::Write),
+            THREADS * ITERATIONS
+        );
+        assert_eq!(
+            file_stats.file(FileType::Any).get(StatField::Access),
+            THREADS * ITERATIONS
+        );
+        assert_eq!(file_stats.file(FileType::Journal).get(StatField::Write), 0);
+    }
+}
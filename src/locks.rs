@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! File lock level transitions, and the `vfsstat_locks` table.
+
+use alloc::{string::ToString, vec, vec::Vec};
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::{
+    table::{Row, Rows, Table},
+    vfs::Vfs,
+};
+
+/// Number of lock levels, `SQLITE_LOCK_NONE` to `SQLITE_LOCK_EXCLUSIVE`.
+pub const LEVELS: usize = 5;
+
+/// Returns the name of a lock level.
+pub const fn level_name(level: usize) -> &'static str {
+    match level {
+        0 => "NONE",
+        1 => "SHARED",
+        2 => "RESERVED",
+        3 => "PENDING",
+        4 => "EXCLUSIVE",
+        _ => "?",
+    }
+}
+
+/// Lock level transitions of a file, indexed by the level the file was in and
+/// the level that was requested.
+#[derive(Debug)]
+pub struct LockStats {
+    /// Successful transitions
+    transitions: [[AtomicU64; LEVELS]; LEVELS],
+    /// `SQLITE_BUSY` results of xLock
+    busy: [[AtomicU64; LEVELS]; LEVELS],
+    /// Time spent in the first level before each transition, in nanoseconds
+    held_ns: [[AtomicU64; LEVELS]; LEVELS],
+}
+
+impl Default for LockStats {
+    fn default() -> Self {
+        Self {
+            transitions: core::array::from_fn(|_| core::array::from_fn(|_| AtomicU64::new(0))),
+            busy: core::array::from_fn(|_| core::array::from_fn(|_| AtomicU64::new(0))),
+            held_ns: core::array::from_fn(|_| core::array::from_fn(|_| AtomicU64::new(0))),
+        }
+    }
+}
+
+impl LockStats {
+    /// Records a transition from `from` to `to` after holding `from` for
+    /// `held`.
+    pub fn transition(&self, from: usize, to: usize, held: Duration) {
+        if from >= LEVELS || to >= LEVELS {
+            return;
+        }
+        let ns = u64::try_from(held.as_nanos()).unwrap_or(u64::MAX);
+        self.transitions[from][to].fetch_add(1, Ordering::Relaxed);
+        self.held_ns[from][to].fetch_add(ns, Ordering::Relaxed);
+    }
+
+    /// Records an `SQLITE_BUSY` result when requesting `to` while in `from`.
+    pub fn busy(&self, from: usize, to: usize) {
+        if from >= LEVELS || to >= LEVELS {
+            return;
+        }
+        self.busy[from][to].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn reset(&self) {
+        for counter in self
+            .transitions
+            .iter()
+            .chain(self.busy.iter())
+            .chain(self.held_ns.iter())
+            .flatten()
+        {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_locks\0",
+    schema: b"CREATE TABLE x(path,filetype,from_level,to_level,count,busy,held_ns)\0",
+    rows: Rows::Scan(rows),
+};
+
+/// One row per path and pair of levels with at least one transition or busy
+/// result.
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    let paths = vfs_.paths.lock().unwrap_or_else(|err| err.into_inner());
+    let mut rows = Vec::new();
+    for (path, path_stats) in paths.iter() {
+        let locks = &path_stats.locks;
+        for from in 0..LEVELS {
+            for to in 0..LEVELS {
+                let count = locks.transitions[from][to].load(Ordering::Relaxed);
+                let busy = locks.busy[from][to].load(Ordering::Relaxed);
+                if count == 0 && busy == 0 {
+                    continue;
+                }
+                rows.push(vec![
+                    path.to_string().into(),
+                    path_stats.filetype.name().into(),
+                    level_name(from).into(),
+                    level_name(to).into(),
+                    count.into(),
+                    busy.into(),
+                    locks.held_ns[from][to].load(Ordering::Relaxed).into(),
+                ]);
+            }
+        }
+    }
+    rows
+}
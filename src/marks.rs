@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Named copies of the `vtabstat` counters, taken with `vfsstat_mark()`, and
+//! the `vfsstat_marks` and `vfsstat_delta` tables.
+
+use alloc::{collections::BTreeMap, format, string::String, vec, vec::Vec};
+use std::{
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    table::{Args, Row, Rows, Table, Value},
+    vfs::Vfs,
+    FileStats, FileType, StatField,
+};
+
+/// Values of every counter, indexed by [`FileType`] and [`StatField`].
+pub type Counts = [[u64; StatField::ALL.len()]; FileType::ALL.len()];
+
+/// Reads every counter of `file_stats`.
+pub fn counts(file_stats: &FileStats) -> Counts {
+    core::array::from_fn(|filetype| {
+        let stats = file_stats.file(FileType::ALL[filetype]);
+        core::array::from_fn(|field| stats.get(StatField::ALL[field]))
+    })
+}
+
+/// A copy of the process-wide counters.
+#[derive(Clone, Debug)]
+pub struct Mark {
+    /// Seconds since the Unix epoch
+    pub taken: f64,
+    pub counts: Counts,
+}
+
+impl Mark {
+    pub fn new(file_stats: &FileStats) -> Self {
+        Self {
+            taken: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0.0, |d| d.as_secs_f64()),
+            counts: counts(file_stats),
+        }
+    }
+}
+
+/// Marks by name.
+#[derive(Debug, Default)]
+pub struct Marks {
+    entries: Mutex<BTreeMap<String, Mark>>,
+}
+
+impl Marks {
+    /// Stores `mark` as `name`, replacing any previous mark of that name.
+    pub fn set(&self, name: &str, mark: Mark) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(name.into(), mark);
+    }
+
+    pub fn get(&self, name: &str) -> Option<Mark> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(name)
+            .cloned()
+    }
+
+    /// Drops the mark `name`, returning whether it existed.
+    pub fn remove(&self, name: &str) -> bool {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(name)
+            .is_some()
+    }
+
+    /// Returns a copy of every mark, by name.
+    pub fn snapshot(&self) -> Vec<(String, Mark)> {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries
+            .iter()
+            .map(|(name, mark)| (name.clone(), mark.clone()))
+            .collect()
+    }
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_marks\0",
+    schema: b"CREATE TABLE x(name,taken)\0",
+    rows: Rows::Scan(rows),
+};
+
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    vfs_.marks
+        .snapshot()
+        .into_iter()
+        .map(|(name, mark)| vec![name.into(), Value::Real(mark.taken)])
+        .collect()
+}
+
+pub static DELTA_TABLE: Table = Table {
+    name: b"vfsstat_delta\0",
+    schema: b"CREATE TABLE x(file,stat,delta,mark_from HIDDEN,mark_to HIDDEN)\0",
+    rows: Rows::Args {
+        columns: &[3, 4],
+        rows: delta_rows,
+    },
+};
+
+/// One row per counter, with its change from `mark_from` to `mark_to`, or to
+/// the current value if `mark_to` is not given.
+fn delta_rows(vfs_: &Vfs, args: &Args) -> Result<Vec<Row>, String> {
+    let find = |name: &str| {
+        vfs_.marks
+            .get(name)
+            .ok_or_else(|| format!("vfsstat_delta: no mark named {:?}", name))
+    };
+    let from = match &args[0] {
+        Some(name) => find(name)?,
+        None => return Err("vfsstat_delta: mark_from is required".into()),
+    };
+    let to = match &args[1] {
+        Some(name) => find(name)?.counts,
+        None => counts(&vfs_.file_stats),
+    };
+    Ok(delta(&from.counts, &to))
+}
+
+/// One row per counter, with its change from `from` to `to`. The change is
+/// `NULL` if the counter decreased, which happens when it was reset in
+/// between.
+fn delta(from: &Counts, to: &Counts) -> Vec<Row> {
+    let mut rows = Vec::with_capacity(FileType::ALL.len() * StatField::ALL.len());
+    for (i, filetype) in FileType::ALL.iter().enumerate() {
+        for (j, field) in StatField::ALL.iter().enumerate() {
+            let delta = match to[i][j].checked_sub(from[i][j]) {
+                Some(delta) => delta.into(),
+                None => Value::Null,
+            };
+            rows.push(vec![filetype.name().into(), field.name().into(), delta]);
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta() {
+        use crate::{statcnt, FileType, StatField};
+
+        let file_stats = FileStats::default();
+        statcnt!(file_stats, FileType::Main, Read += 3);
+        statcnt!(file_stats, FileType::Wal, Write += 5);
+        let from = counts(&file_stats);
+        statcnt!(file_stats, FileType::Main, Read += 4);
+        file_stats.file(FileType::Wal).reset();
+        let to = counts(&file_stats);
+        let rows = delta(&from, &to);
+        assert_eq!(rows.len(), FileType::ALL.len() * StatField::ALL.len());
+        let find = |file: FileType, stat: StatField| {
+            rows.iter()
+                .find(|row| match (&row[0], &row[1]) {
+                    (Value::Str(f), Value::Str(s)) => *f == file.name() && *s == stat.name(),
+                    _ => false,
+                })
+                .map(|row| row[2].clone())
+                .unwrap()
+        };
+        assert!(matches!(
+            find(FileType::Main, StatField::Read),
+            Value::Int(4)
+        ));
+        assert!(matches!(
+            find(FileType::Main, StatField::Write),
+            Value::Int(0)
+        ));
+        // Reset in between
+        assert!(matches!(find(FileType::Wal, StatField::Write), Value::Null));
+    }
+}
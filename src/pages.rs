@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Per-page access counts of database files, and the `vfsstat_pages` table.
+
+use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+    table::{Row, Rows, Table, Value},
+    vfs::Vfs,
+};
+
+/// Offset of the page size in the database header.
+const HEADER_PAGE_SIZE_OFFSET: usize = 16;
+
+/// Access counts of one page.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PageStats {
+    pub reads: u64,
+    pub writes: u64,
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+}
+
+impl PageStats {
+    fn record(&mut self, write: bool, amt: u64) {
+        if write {
+            self.writes += 1;
+            self.bytes_out += amt;
+        } else {
+            self.reads += 1;
+            self.bytes_in += amt;
+        }
+    }
+
+    /// Adds the counts of `other` to `self`.
+    pub fn add(&mut self, other: &Self) {
+        self.reads += other.reads;
+        self.writes += other.writes;
+        self.bytes_in += other.bytes_in;
+        self.bytes_out += other.bytes_out;
+    }
+}
+
+/// Page heatmap of a database file.
+#[derive(Debug, Default)]
+pub struct PageMap {
+    /// Page size, `0` until it is known
+    pub page_size: u32,
+    pub pages: BTreeMap<u32, PageStats>,
+    /// Accesses that could not be attributed to a page, because the page size
+    /// was not known yet or the page limit was reached
+    pub untracked: PageStats,
+}
+
+impl PageMap {
+    /// Records a successful read or write of `buf` at `offset`.
+    ///
+    /// `tracked` is the number of pages tracked across all files; a new page
+    /// is only added while it is below `max_pages`.
+    pub fn record(
+        &mut self,
+        write: bool,
+        buf: &[u8],
+        offset: u64,
+        tracked: &AtomicUsize,
+        max_pages: usize,
+    ) {
+        let amt = buf.len() as u64;
+        if offset == 0 {
+            if let Some(page_size) = header_page_size(buf) {
+                self.page_size = page_size;
+            }
+        }
+        if self.page_size == 0 && offset % amt.max(1) == 0 && is_page_size(amt) {
+            // No header seen yet: assume the first aligned page-sized access
+            // is one page.
+            self.page_size = amt as u32;
+        }
+        if self.page_size == 0 {
+            self.untracked.record(write, amt);
+            return;
+        }
+        let pgno = match u32::try_from(offset / u64::from(self.page_size) + 1) {
+            Ok(pgno) => pgno,
+            Err(_) => {
+                self.untracked.record(write, amt);
+                return;
+            }
+        };
+        if let Some(page) = self.pages.get_mut(&pgno) {
+            page.record(write, amt);
+        } else if tracked.fetch_add(1, Ordering::Relaxed) < max_pages {
+            let mut page = PageStats::default();
+            page.record(write, amt);
+            self.pages.insert(pgno, page);
+        } else {
+            tracked.fetch_sub(1, Ordering::Relaxed);
+            self.untracked.record(write, amt);
+        }
+    }
+
+    /// Forgets all pages, returning how many were tracked.
+    pub fn clear(&mut self) -> usize {
+        let len = self.pages.len();
+        self.pages.clear();
+        self.untracked = PageStats::default();
+        len
+    }
+}
+
+const fn is_page_size(amt: u64) -> bool {
+    amt.is_power_of_two() && amt >= 512 && amt <= 65536
+}
+
+/// Reads the page size from a buffer holding the start of a database file.
+fn header_page_size(buf: &[u8]) -> Option<u32> {
+    if !buf.starts_with(b"SQLite format 3\0") || buf.len() < HEADER_PAGE_SIZE_OFFSET + 2 {
+        return None;
+    }
+    let raw = u16::from_be_bytes([
+        buf[HEADER_PAGE_SIZE_OFFSET],
+        buf[HEADER_PAGE_SIZE_OFFSET + 1],
+    ]);
+    let page_size = if raw == 1 { 65536 } else { u32::from(raw) };
+    if is_page_size(page_size.into()) {
+        Some(page_size)
+    } else {
+        None
+    }
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_pages\0",
+    schema: b"CREATE TABLE x(path,pgno,reads,writes,bytes_in,bytes_out)\0",
+    rows: Rows::Scan(rows),
+};
+
+/// One row per tracked page, and one row with a `NULL` page number for the
+/// untracked accesses of each file.  Open databases without a name are listed
+/// separately under their shared path.
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    let paths = vfs_.paths.lock().unwrap_or_else(|err| err.into_inner());
+    let mut rows = Vec::new();
+    for (path, path_stats) in paths.iter() {
+        let unnamed_pages = path_stats
+            .unnamed_pages
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        for pages in
+            core::iter::once(&path_stats.pages).chain(unnamed_pages.iter().map(Arc::as_ref))
+        {
+            let pages = pages.lock().unwrap_or_else(|err| err.into_inner());
+            let untracked = (pages.untracked.reads != 0 || pages.untracked.writes != 0)
+                .then(|| (Value::Null, pages.untracked));
+            for (pgno, page) in pages
+                .pages
+                .iter()
+                .map(|(pgno, page)| (Value::Int((*pgno).into()), *page))
+                .chain(untracked)
+            {
+                rows.push(vec![
+                    path.to_string().into(),
+                    pgno,
+                    page.reads.into(),
+                    page.writes.into(),
+                    page.bytes_in.into(),
+                    page.bytes_out.into(),
+                ]);
+            }
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_page_size() {
+        let mut header = [0_u8; 100];
+        header[..16].copy_from_slice(b"SQLite format 3\0");
+        header[16..18].copy_from_slice(&4096_u16.to_be_bytes());
+        assert_eq!(header_page_size(&header), Some(4096));
+        header[16..18].copy_from_slice(&1_u16.to_be_bytes());
+        assert_eq!(header_page_size(&header), Some(65536));
+        header[16..18].copy_from_slice(&1000_u16.to_be_bytes());
+        assert_eq!(header_page_size(&header), None);
+        assert_eq!(header_page_size(&header[..17]), None);
+        assert_eq!(header_page_size(&[0; 100]), None);
+    }
+
+    #[test]
+    fn test_page_map_record() {
+        let tracked = AtomicUsize::new(0);
+        let mut map = PageMap::default();
+        // Unaligned accesses before the page size is known are untracked.
+        map.record(false, &[0; 100], 24, &tracked, 2);
+        assert_eq!(map.page_size, 0);
+        assert_eq!(map.untracked.reads, 1);
+        // The header gives the page size.
+        let mut header = [0_u8; 100];
+        header[..16].copy_from_slice(b"SQLite format 3\0");
+        header[16..18].copy_from_slice(&1024_u16.to_be_bytes());
+        map.record(false, &header, 0, &tracked, 2);
+        assert_eq!(map.page_size, 1024);
+        map.record(true, &[0; 1024], 2048, &tracked, 2);
+        map.record(true, &[0; 1024], 2048, &tracked, 2);
+        assert_eq!(map.pages[&1].reads, 1);
+        assert_eq!(map.pages[&3].writes, 2);
+        assert_eq!(map.pages[&3].bytes_out, 2048);
+        // Past max_pages, new pages are untracked.
+        map.record(false, &[0; 1024], 4096, &tracked, 2);
+        assert!(!map.pages.contains_key(&5));
+        assert_eq!(map.untracked.reads, 2);
+        assert_eq!(tracked.load(Ordering::Relaxed), 2);
+        assert_eq!(map.clear(), 2);
+    }
+}
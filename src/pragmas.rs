@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `vfsstat*` pragmas, answered through `SQLITE_FCNTL_PRAGMA` by the main
+//! database file of a stat VFS.
+//!
+//! - `PRAGMA vfsstat` returns the statistics as JSON, and
+//!   `PRAGMA vfsstat = prometheus` in the Prometheus text format.
+//! - `PRAGMA vfsstat_reset` zeroes the statistics.
+//! - `PRAGMA vfsstat_config` lists the settings, `PRAGMA vfsstat_config(key)`
+//!   returns one and `PRAGMA vfsstat_config('key=value')` changes one.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    config::Config,
+    json::to_json,
+    prometheus::to_prometheus,
+    sqlite3ext::{SQLITE_ERROR, SQLITE_NOTFOUND, SQLITE_OK},
+    vfs::Vfs,
+};
+
+/// Answers the pragma in `azArg`, as given to `SQLITE_FCNTL_PRAGMA`.
+///
+/// Returns `SQLITE_NOTFOUND` for pragmas that are not ours, so that SQLite
+/// handles them.
+///
+/// # Safety
+///
+/// `azArg` must be the array of three strings passed with
+/// `SQLITE_FCNTL_PRAGMA`, whose first element SQLite frees with
+/// `sqlite3_free()`.
+pub unsafe fn file_control(vfs_: &Vfs, azArg: *mut *mut ::core::ffi::c_char) -> ::core::ffi::c_int {
+    let arg = |i: usize| {
+        let ptr = unsafe { *azArg.add(i) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { ::core::ffi::CStr::from_ptr(ptr) }.to_string_lossy())
+        }
+    };
+    let name = if let Some(name) = arg(1) {
+        name.to_ascii_lowercase()
+    } else {
+        return SQLITE_NOTFOUND as _;
+    };
+    let value = arg(2);
+    let result = match name.as_str() {
+        "vfsstat" => vfsstat(vfs_, value.as_deref()),
+        "vfsstat_reset" => {
+            vfs_.reset();
+            Ok(None)
+        }
+        "vfsstat_config" => config(&vfs_.config, value.as_deref()),
+        _ => return SQLITE_NOTFOUND as _,
+    };
+    let (rc, text) = match result {
+        Ok(text) => (SQLITE_OK, text),
+        Err(err) => (SQLITE_ERROR, Some(format!("PRAGMA {}: {}", name, err))),
+    };
+    // SQLite frees azArg[0] with sqlite3_free().
+    if let Some(text) = text {
+        unsafe { *azArg = crate::err_to_sqlite3_str(text).unwrap_or(core::ptr::null_mut()) };
+    }
+    rc as _
+}
+
+fn vfsstat(vfs_: &Vfs, format: Option<&str>) -> Result<Option<String>, String> {
+    match format {
+        None => Ok(Some(to_json(vfs_, None))),
+        Some(f) if f.eq_ignore_ascii_case("json") => Ok(Some(to_json(vfs_, None))),
+        Some(f) if f.eq_ignore_ascii_case("prometheus") => Ok(Some(to_prometheus(vfs_))),
+        Some(f) => Err(format!("Unknown format {:?}", f)),
+    }
+}
+
+/// Lists the settings, returns one with `key` or changes one with
+/// `key=value`, returning its new value.
+fn config(config: &Config, value: Option<&str>) -> Result<Option<String>, String> {
+    let value = if let Some(value) = value {
+        value
+    } else {
+        let settings = Config::KEYS
+            .iter()
+            .map(|key| Ok(format!("{}={}", key, config.get(key)?)))
+            .collect::<Result<Vec<_>, String>>()?;
+        return Ok(Some(settings.join(" ")));
+    };
+    let key = match value.split_once('=') {
+        Some((key, new)) => {
+            let key = key.trim();
+            config.set(key, new)?;
+            key
+        }
+        None => value.trim(),
+    };
+    Ok(Some(config.get(key)?.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config() {
+        let settings = Config::default();
+        assert_eq!(
+            config(&settings, None).unwrap().unwrap(),
+            "max_pages=65536 max_statements=1024 max_commits=1000 trace_statements=0"
+        );
+        assert_eq!(
+            config(&settings, Some(" max_commits ")).unwrap().unwrap(),
+            "1000"
+        );
+        assert_eq!(
+            config(&settings, Some("max_commits = 10"))
+                .unwrap()
+                .unwrap(),
+            "10"
+        );
+        assert_eq!(settings.get("max_commits").unwrap(), 10);
+        assert!(config(&settings, Some("nope")).is_err());
+        assert!(config(&settings, Some("nope=1")).is_err());
+        assert!(config(&settings, Some("max_commits=-1")).is_err());
+        assert!(config(&settings, Some("max_commits=")).is_err());
+        assert_eq!(settings.get("max_commits").unwrap(), 10);
+    }
+}
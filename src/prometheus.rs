@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Prometheus text exposition of the statistics, returned by
+//! `vfsstat_prometheus()`.
+//!
+//! Metrics, all labelled with `file` (the file type) and `op` (the method)
+//! unless noted:
+//!
+//! - `vfsstat_calls_total`: the call counters of `vtabstat`,
+//! - `vfsstat_bytes_total`: the byte counters of `vtabstat`, with the `op`
+//!   `read` for `bytesIn`, `write` for `bytesOut` and `fetch` for
+//!   `bytesFetched`,
+//! - `vfsstat_latency_seconds`: histogram of the time spent in the parent VFS,
+//! - `vfsstat_request_size_bytes`: histogram of the read, write and fetch
+//!   request sizes,
+//! - `vfsstat_commit_duration_seconds`, `vfsstat_commit_bytes` and
+//!   `vfsstat_commit_syncs`: histograms of the committed transactions, without
+//!   labels.
+//!
+//! Histograms only have series for the label values that have been recorded,
+//! and buckets up to the largest recorded value.
+
+use alloc::string::String;
+use core::{fmt::Write, sync::atomic::Ordering};
+
+use crate::{
+    histogram::{Histogram, BUCKETS},
+    iosize,
+    vfs::Vfs,
+    FileType, IoOp, StatField,
+};
+
+/// Serializes the process-wide statistics of `vfs_`.
+pub fn to_prometheus(vfs_: &Vfs) -> String {
+    let mut out = String::new();
+
+    header(
+        &mut out,
+        "vfsstat_calls_total",
+        "counter",
+        "Calls to the VFS and file methods.",
+    );
+    for filetype in FileType::ALL {
+        let stats = vfs_.file_stats.file(filetype);
+        for field in StatField::ALL {
+            if bytes_op(field).is_none() {
+                let _ = writeln!(
+                    out,
+                    "vfsstat_calls_total{{file=\"{}\",op=\"{}\"}} {}",
+                    filetype.name(),
+                    field.name(),
+                    stats.get(field)
+                );
+            }
+        }
+    }
+
+    header(
+        &mut out,
+        "vfsstat_bytes_total",
+        "counter",
+        "Bytes read, written and fetched.",
+    );
+    for filetype in FileType::ALL {
+        let stats = vfs_.file_stats.file(filetype);
+        for field in StatField::ALL {
+            if let Some(op) = bytes_op(field) {
+                let _ = writeln!(
+                    out,
+                    "vfsstat_bytes_total{{file=\"{}\",op=\"{}\"}} {}",
+                    filetype.name(),
+                    op,
+                    stats.get(field)
+                );
+            }
+        }
+    }
+
+    header(
+        &mut out,
+        "vfsstat_latency_seconds",
+        "histogram",
+        "Time spent in the parent VFS.",
+    );
+    for filetype in FileType::ALL {
+        for op in IoOp::ALL {
+            let stats = vfs_.latency.get(filetype, op);
+            if stats.count.load(Ordering::Relaxed) == 0 {
+                continue;
+            }
+            histogram(
+                &mut out,
+                "vfsstat_latency_seconds",
+                &labels(filetype, op.name()),
+                &stats.histogram.snapshot(),
+                stats.total_ns.load(Ordering::Relaxed),
+                NANOS_PER_SEC,
+            );
+        }
+    }
+
+    header(
+        &mut out,
+        "vfsstat_request_size_bytes",
+        "histogram",
+        "Sizes of read, write and fetch requests.",
+    );
+    for filetype in FileType::ALL {
+        for op in iosize::OPS {
+            let sizes = vfs_.iosize.get(filetype, op).unwrap();
+            let counts = sizes.snapshot();
+            if counts.iter().all(|count| *count == 0) {
+                continue;
+            }
+            histogram(
+                &mut out,
+                "vfsstat_request_size_bytes",
+                &labels(filetype, op.name()),
+                &counts,
+                sizes.sum(),
+                1.0,
+            );
+        }
+    }
+
+    for (metric, help, scale, commits) in [
+        (
+            "vfsstat_commit_duration_seconds",
+            "Durations of the committed write transactions.",
+            NANOS_PER_SEC,
+            &vfs_.commits.duration_ns,
+        ),
+        (
+            "vfsstat_commit_bytes",
+            "Bytes written by the committed write transactions.",
+            1.0,
+            &vfs_.commits.bytes,
+        ),
+        (
+            "vfsstat_commit_syncs",
+            "Syncs of the committed write transactions.",
+            1.0,
+            &vfs_.commits.syncs,
+        ),
+    ] {
+        header(&mut out, metric, "histogram", help);
+        histogram(
+            &mut out,
+            metric,
+            "",
+            &commits.snapshot(),
+            commits.sum(),
+            scale,
+        );
+    }
+    out
+}
+
+const NANOS_PER_SEC: f64 = 1_000_000_000.0;
+
+/// The `op` label of the byte counters, `None` for call counters.
+const fn bytes_op(field: StatField) -> Option<&'static str> {
+    match field {
+        StatField::BytesIn => Some("read"),
+        StatField::BytesOut => Some("write"),
+        StatField::BytesFetched => Some("fetch"),
+        _ => None,
+    }
+}
+
+fn labels(filetype: FileType, op: &str) -> String {
+    alloc::format!("file=\"{}\",op=\"{}\"", filetype.name(), op)
+}
+
+fn header(out: &mut String, metric: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", metric, help);
+    let _ = writeln!(out, "# TYPE {} {}", metric, kind);
+}
+
+/// Writes the cumulative buckets, sum and count of a histogram whose values
+/// are divided by `scale` in the output.
+fn histogram(
+    out: &mut String,
+    metric: &str,
+    labels: &str,
+    counts: &[u64; BUCKETS],
+    sum: u64,
+    scale: f64,
+) {
+    let sep = if labels.is_empty() { "" } else { "," };
+    let last = counts.iter().rposition(|count| *count != 0);
+    let mut cumulative = 0;
+    for (bucket, count) in counts.iter().enumerate().take(last.map_or(0, |l| l + 1)) {
+        cumulative += count;
+        let (_, hi) = Histogram::bounds(bucket);
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{}{}le=\"{}\"}} {}",
+            metric,
+            labels,
+            sep,
+            hi as f64 / scale,
+            cumulative
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+        metric, labels, sep, cumulative
+    );
+    let braces = if labels.is_empty() {
+        String::new()
+    } else {
+        alloc::format!("{{{}}}", labels)
+    };
+    let _ = writeln!(out, "{}_sum{} {}", metric, braces, sum as f64 / scale);
+    let _ = writeln!(out, "{}_count{} {}", metric, braces, cumulative);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram() {
+        use crate::histogram::BUCKETS;
+
+        let mut counts = [0; BUCKETS];
+        counts[0] = 1; // 0
+        counts[2] = 2; // 2..=3
+        counts[3] = 1; // 4..=7
+        let mut out = String::new();
+        histogram(&mut out, "m", "op=\"read\"", &counts, 12, 1.0);
+        assert_eq!(
+            out,
+            "m_bucket{op=\"read\",le=\"0\"} 1\n\
+             m_bucket{op=\"read\",le=\"1\"} 1\n\
+             m_bucket{op=\"read\",le=\"3\"} 3\n\
+             m_bucket{op=\"read\",le=\"7\"} 4\n\
+             m_bucket{op=\"read\",le=\"+Inf\"} 4\n\
+             m_sum{op=\"read\"} 12\n\
+             m_count{op=\"read\"} 4\n"
+        );
+
+        // Without labels, scaled, and empty
+        let mut out = String::new();
+        histogram(&mut out, "m", "", &[0; BUCKETS], 0, 1e9);
+        assert_eq!(out, "m_bucket{le=\"+Inf\"} 0\nm_sum 0\nm_count 0\n");
+        counts = [0; BUCKETS];
+        counts[11] = 2; // 1024..=2047 ns
+        let mut out = String::new();
+        histogram(&mut out, "m", "", &counts, 3000, 1e9);
+        assert!(out.ends_with(
+            "m_bucket{le=\"0.000002047\"} 2\n\
+             m_bucket{le=\"+Inf\"} 2\n\
+             m_sum 0.000003\n\
+             m_count 2\n"
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! WAL-index shared-memory lock statistics, and the `vfsstat_shm_locks`
+//! table.
+
+use alloc::{vec, vec::Vec};
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::{
+    sqlite3ext::SQLITE_SHM_NLOCK,
+    table::{Row, Rows, Table, Value},
+    vfs::Vfs,
+};
+
+/// Number of shm lock slots.
+pub const SLOTS: usize = SQLITE_SHM_NLOCK as usize;
+
+/// Lock modes, indexed by [`mode_index`].
+pub const MODES: [&str; 2] = ["shared", "exclusive"];
+
+/// Returns the index in [`MODES`] of the mode in the `flags` of xShmLock.
+pub const fn mode_index(exclusive: bool) -> usize {
+    exclusive as usize
+}
+
+/// Returns the name of a shm lock slot as used by the WAL implementation.
+pub const fn slot_name(slot: usize) -> &'static str {
+    match slot {
+        0 => "WRITER",
+        1 => "CKPT",
+        2 => "RECOVER",
+        3 => "READ0",
+        4 => "READ1",
+        5 => "READ2",
+        6 => "READ3",
+        7 => "READ4",
+        _ => "?",
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ShmLockStats {
+    /// Successful lock requests
+    pub acquired: AtomicU64,
+    /// `SQLITE_BUSY` results of lock requests
+    pub busy: AtomicU64,
+    /// Successful unlock requests
+    pub released: AtomicU64,
+    /// Total time the lock was held, in nanoseconds
+    pub total_hold_ns: AtomicU64,
+}
+
+impl ShmLockStats {
+    pub fn reset(&self) {
+        self.acquired.store(0, Ordering::Relaxed);
+        self.busy.store(0, Ordering::Relaxed);
+        self.released.store(0, Ordering::Relaxed);
+        self.total_hold_ns.store(0, Ordering::Relaxed);
+    }
+}
+
+/// [`ShmLockStats`] of every slot and mode.
+#[derive(Debug)]
+pub struct ShmLocks {
+    stats: [[ShmLockStats; MODES.len()]; SLOTS],
+}
+
+impl Default for ShmLocks {
+    fn default() -> Self {
+        Self {
+            stats: core::array::from_fn(|_| core::array::from_fn(|_| ShmLockStats::default())),
+        }
+    }
+}
+
+impl ShmLocks {
+    /// Returns the statistics of `slot` in `mode`, see [`mode_index`].
+    pub fn get(&self, slot: usize, mode: usize) -> Option<&ShmLockStats> {
+        self.stats.get(slot)?.get(mode)
+    }
+
+    /// Adds `held` to the hold time of `slot` in `mode`.
+    pub fn released(&self, slot: usize, mode: usize, held: Duration) {
+        if let Some(stats) = self.get(slot, mode) {
+            let ns = u64::try_from(held.as_nanos()).unwrap_or(u64::MAX);
+            stats.total_hold_ns.fetch_add(ns, Ordering::Relaxed);
+        }
+    }
+
+    pub fn reset(&self) {
+        for stats in self.stats.iter().flatten() {
+            stats.reset();
+        }
+    }
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_shm_locks\0",
+    schema: b"CREATE TABLE x(slot,slot_name,mode,acquired,busy,released,total_hold_ns)\0",
+    rows: Rows::Scan(rows),
+};
+
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    let mut rows = Vec::with_capacity(SLOTS * MODES.len());
+    for slot in 0..SLOTS {
+        for (mode, mode_name) in MODES.iter().enumerate() {
+            let stats = vfs_.shm_locks.get(slot, mode).unwrap();
+            rows.push(vec![
+                Value::Int(slot as i64),
+                slot_name(slot).into(),
+                (*mode_name).into(),
+                stats.acquired.load(Ordering::Relaxed).into(),
+                stats.busy.load(Ordering::Relaxed).into(),
+                stats.released.load(Ordering::Relaxed).into(),
+                stats.total_hold_ns.load(Ordering::Relaxed).into(),
+            ]);
+        }
+    }
+    rows
+}
@@ -1,6 +1,5 @@
 // SPDX-License-Identifier: blessing
 /* automatically @generated by rust-bindgen 0.59.1 */
-#![allow(deref_nullptr)]
 
 pub const __GNUC_VA_LIST: u32 = 1;
 pub const SQLITE_VERSION: &[u8; 7usize] = b"3.34.1\0";
@@ -531,7 +530,11 @@ fn bindgen_test_layout_sqlite3_file() {
         concat!("Alignment of ", stringify!(sqlite3_file))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_file>())).pMethods as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_file>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pMethods) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -660,7 +663,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         concat!("Alignment of ", stringify!(sqlite3_io_methods))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).iVersion as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iVersion) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -670,7 +677,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xClose as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xClose) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -680,7 +691,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xRead as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRead) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -690,7 +705,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xWrite as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xWrite) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -700,7 +719,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xTruncate as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xTruncate) as usize - ptr as usize }
+        },
         32usize,
         concat!(
             "Offset of field: ",
@@ -710,7 +733,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xSync as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xSync) as usize - ptr as usize }
+        },
         40usize,
         concat!(
             "Offset of field: ",
@@ -720,7 +747,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xFileSize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFileSize) as usize - ptr as usize }
+        },
         48usize,
         concat!(
             "Offset of field: ",
@@ -730,7 +761,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xLock as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xLock) as usize - ptr as usize }
+        },
         56usize,
         concat!(
             "Offset of field: ",
@@ -740,7 +775,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xUnlock as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xUnlock) as usize - ptr as usize }
+        },
         64usize,
         concat!(
             "Offset of field: ",
@@ -750,8 +789,10 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_io_methods>())).xCheckReservedLock as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCheckReservedLock) as usize - ptr as usize }
         },
         72usize,
         concat!(
@@ -762,8 +803,10 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_io_methods>())).xFileControl as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFileControl) as usize - ptr as usize }
         },
         80usize,
         concat!(
@@ -774,7 +817,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xSectorSize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xSectorSize) as usize - ptr as usize }
+        },
         88usize,
         concat!(
             "Offset of field: ",
@@ -784,9 +831,10 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_io_methods>())).xDeviceCharacteristics as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDeviceCharacteristics) as usize - ptr as usize }
         },
         96usize,
         concat!(
@@ -797,7 +845,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xShmMap as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShmMap) as usize - ptr as usize }
+        },
         104usize,
         concat!(
             "Offset of field: ",
@@ -807,7 +859,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xShmLock as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShmLock) as usize - ptr as usize }
+        },
         112usize,
         concat!(
             "Offset of field: ",
@@ -817,7 +873,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xShmBarrier as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShmBarrier) as usize - ptr as usize }
+        },
         120usize,
         concat!(
             "Offset of field: ",
@@ -827,7 +887,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xShmUnmap as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShmUnmap) as usize - ptr as usize }
+        },
         128usize,
         concat!(
             "Offset of field: ",
@@ -837,7 +901,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xFetch as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFetch) as usize - ptr as usize }
+        },
         136usize,
         concat!(
             "Offset of field: ",
@@ -847,7 +915,11 @@ fn bindgen_test_layout_sqlite3_io_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_io_methods>())).xUnfetch as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_io_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xUnfetch) as usize - ptr as usize }
+        },
         144usize,
         concat!(
             "Offset of field: ",
@@ -995,7 +1067,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         concat!("Alignment of ", stringify!(sqlite3_vfs))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).iVersion as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iVersion) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1005,7 +1081,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).szOsFile as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).szOsFile) as usize - ptr as usize }
+        },
         4usize,
         concat!(
             "Offset of field: ",
@@ -1015,7 +1095,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).mxPathname as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).mxPathname) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -1025,7 +1109,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).pNext as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pNext) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -1035,7 +1123,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).zName as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).zName) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -1045,7 +1137,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).pAppData as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pAppData) as usize - ptr as usize }
+        },
         32usize,
         concat!(
             "Offset of field: ",
@@ -1055,7 +1151,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xOpen as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xOpen) as usize - ptr as usize }
+        },
         40usize,
         concat!(
             "Offset of field: ",
@@ -1065,7 +1165,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xDelete as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDelete) as usize - ptr as usize }
+        },
         48usize,
         concat!(
             "Offset of field: ",
@@ -1075,7 +1179,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xAccess as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xAccess) as usize - ptr as usize }
+        },
         56usize,
         concat!(
             "Offset of field: ",
@@ -1085,7 +1193,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xFullPathname as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFullPathname) as usize - ptr as usize }
+        },
         64usize,
         concat!(
             "Offset of field: ",
@@ -1095,7 +1207,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xDlOpen as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDlOpen) as usize - ptr as usize }
+        },
         72usize,
         concat!(
             "Offset of field: ",
@@ -1105,7 +1221,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xDlError as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDlError) as usize - ptr as usize }
+        },
         80usize,
         concat!(
             "Offset of field: ",
@@ -1115,7 +1235,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xDlSym as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDlSym) as usize - ptr as usize }
+        },
         88usize,
         concat!(
             "Offset of field: ",
@@ -1125,7 +1249,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xDlClose as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDlClose) as usize - ptr as usize }
+        },
         96usize,
         concat!(
             "Offset of field: ",
@@ -1135,7 +1263,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xRandomness as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRandomness) as usize - ptr as usize }
+        },
         104usize,
         concat!(
             "Offset of field: ",
@@ -1145,7 +1277,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xSleep as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xSleep) as usize - ptr as usize }
+        },
         112usize,
         concat!(
             "Offset of field: ",
@@ -1155,7 +1291,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xCurrentTime as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCurrentTime) as usize - ptr as usize }
+        },
         120usize,
         concat!(
             "Offset of field: ",
@@ -1165,7 +1305,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xGetLastError as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xGetLastError) as usize - ptr as usize }
+        },
         128usize,
         concat!(
             "Offset of field: ",
@@ -1175,7 +1319,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xCurrentTimeInt64 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCurrentTimeInt64) as usize - ptr as usize }
+        },
         136usize,
         concat!(
             "Offset of field: ",
@@ -1185,7 +1333,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xSetSystemCall as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xSetSystemCall) as usize - ptr as usize }
+        },
         144usize,
         concat!(
             "Offset of field: ",
@@ -1195,7 +1347,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xGetSystemCall as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xGetSystemCall) as usize - ptr as usize }
+        },
         152usize,
         concat!(
             "Offset of field: ",
@@ -1205,7 +1361,11 @@ fn bindgen_test_layout_sqlite3_vfs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vfs>())).xNextSystemCall as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vfs>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xNextSystemCall) as usize - ptr as usize }
+        },
         160usize,
         concat!(
             "Offset of field: ",
@@ -1272,7 +1432,11 @@ fn bindgen_test_layout_sqlite3_mem_methods() {
         concat!("Alignment of ", stringify!(sqlite3_mem_methods))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_mem_methods>())).xMalloc as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mem_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMalloc) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -1282,7 +1446,11 @@ fn bindgen_test_layout_sqlite3_mem_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_mem_methods>())).xFree as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mem_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFree) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -1292,7 +1460,11 @@ fn bindgen_test_layout_sqlite3_mem_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_mem_methods>())).xRealloc as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mem_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRealloc) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -1302,7 +1474,11 @@ fn bindgen_test_layout_sqlite3_mem_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_mem_methods>())).xSize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mem_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xSize) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -1312,7 +1488,11 @@ fn bindgen_test_layout_sqlite3_mem_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_mem_methods>())).xRoundup as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mem_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRoundup) as usize - ptr as usize }
+        },
         32usize,
         concat!(
             "Offset of field: ",
@@ -1322,7 +1502,11 @@ fn bindgen_test_layout_sqlite3_mem_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_mem_methods>())).xInit as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mem_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xInit) as usize - ptr as usize }
+        },
         40usize,
         concat!(
             "Offset of field: ",
@@ -1332,7 +1516,11 @@ fn bindgen_test_layout_sqlite3_mem_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_mem_methods>())).xShutdown as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mem_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShutdown) as usize - ptr as usize }
+        },
         48usize,
         concat!(
             "Offset of field: ",
@@ -1342,7 +1530,11 @@ fn bindgen_test_layout_sqlite3_mem_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_mem_methods>())).pAppData as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mem_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pAppData) as usize - ptr as usize }
+        },
         56usize,
         concat!(
             "Offset of field: ",
@@ -2665,7 +2857,11 @@ fn bindgen_test_layout_sqlite3_module() {
         concat!("Alignment of ", stringify!(sqlite3_module))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).iVersion as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iVersion) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -2675,7 +2871,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xCreate as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCreate) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -2685,7 +2885,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xConnect as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xConnect) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -2695,7 +2899,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xBestIndex as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xBestIndex) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -2705,7 +2913,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xDisconnect as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDisconnect) as usize - ptr as usize }
+        },
         32usize,
         concat!(
             "Offset of field: ",
@@ -2715,7 +2927,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xDestroy as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDestroy) as usize - ptr as usize }
+        },
         40usize,
         concat!(
             "Offset of field: ",
@@ -2725,7 +2941,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xOpen as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xOpen) as usize - ptr as usize }
+        },
         48usize,
         concat!(
             "Offset of field: ",
@@ -2735,7 +2955,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xClose as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xClose) as usize - ptr as usize }
+        },
         56usize,
         concat!(
             "Offset of field: ",
@@ -2745,7 +2969,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xFilter as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFilter) as usize - ptr as usize }
+        },
         64usize,
         concat!(
             "Offset of field: ",
@@ -2755,7 +2983,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xNext as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xNext) as usize - ptr as usize }
+        },
         72usize,
         concat!(
             "Offset of field: ",
@@ -2765,7 +2997,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xEof as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xEof) as usize - ptr as usize }
+        },
         80usize,
         concat!(
             "Offset of field: ",
@@ -2775,7 +3011,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xColumn as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xColumn) as usize - ptr as usize }
+        },
         88usize,
         concat!(
             "Offset of field: ",
@@ -2785,7 +3025,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xRowid as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRowid) as usize - ptr as usize }
+        },
         96usize,
         concat!(
             "Offset of field: ",
@@ -2795,7 +3039,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xUpdate as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xUpdate) as usize - ptr as usize }
+        },
         104usize,
         concat!(
             "Offset of field: ",
@@ -2805,7 +3053,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xBegin as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xBegin) as usize - ptr as usize }
+        },
         112usize,
         concat!(
             "Offset of field: ",
@@ -2815,7 +3067,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xSync as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xSync) as usize - ptr as usize }
+        },
         120usize,
         concat!(
             "Offset of field: ",
@@ -2825,7 +3081,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xCommit as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCommit) as usize - ptr as usize }
+        },
         128usize,
         concat!(
             "Offset of field: ",
@@ -2835,7 +3095,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xRollback as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRollback) as usize - ptr as usize }
+        },
         136usize,
         concat!(
             "Offset of field: ",
@@ -2845,7 +3109,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xFindFunction as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFindFunction) as usize - ptr as usize }
+        },
         144usize,
         concat!(
             "Offset of field: ",
@@ -2855,7 +3123,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xRename as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRename) as usize - ptr as usize }
+        },
         152usize,
         concat!(
             "Offset of field: ",
@@ -2865,7 +3137,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xSavepoint as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xSavepoint) as usize - ptr as usize }
+        },
         160usize,
         concat!(
             "Offset of field: ",
@@ -2875,7 +3151,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xRelease as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRelease) as usize - ptr as usize }
+        },
         168usize,
         concat!(
             "Offset of field: ",
@@ -2885,7 +3165,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xRollbackTo as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRollbackTo) as usize - ptr as usize }
+        },
         176usize,
         concat!(
             "Offset of field: ",
@@ -2895,7 +3179,11 @@ fn bindgen_test_layout_sqlite3_module() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_module>())).xShadowName as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_module>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShadowName) as usize - ptr as usize }
+        },
         184usize,
         concat!(
             "Offset of field: ",
@@ -2949,9 +3237,11 @@ fn bindgen_test_layout_sqlite3_index_info_sqlite3_index_constraint() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info_sqlite3_index_constraint>())).iColumn
-                as *const _ as usize
+        {
+            let uninit =
+                ::core::mem::MaybeUninit::<sqlite3_index_info_sqlite3_index_constraint>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iColumn) as usize - ptr as usize }
         },
         0usize,
         concat!(
@@ -2962,9 +3252,11 @@ fn bindgen_test_layout_sqlite3_index_info_sqlite3_index_constraint() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info_sqlite3_index_constraint>())).op as *const _
-                as usize
+        {
+            let uninit =
+                ::core::mem::MaybeUninit::<sqlite3_index_info_sqlite3_index_constraint>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).op) as usize - ptr as usize }
         },
         4usize,
         concat!(
@@ -2975,9 +3267,11 @@ fn bindgen_test_layout_sqlite3_index_info_sqlite3_index_constraint() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info_sqlite3_index_constraint>())).usable
-                as *const _ as usize
+        {
+            let uninit =
+                ::core::mem::MaybeUninit::<sqlite3_index_info_sqlite3_index_constraint>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).usable) as usize - ptr as usize }
         },
         5usize,
         concat!(
@@ -2988,9 +3282,11 @@ fn bindgen_test_layout_sqlite3_index_info_sqlite3_index_constraint() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info_sqlite3_index_constraint>())).iTermOffset
-                as *const _ as usize
+        {
+            let uninit =
+                ::core::mem::MaybeUninit::<sqlite3_index_info_sqlite3_index_constraint>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iTermOffset) as usize - ptr as usize }
         },
         8usize,
         concat!(
@@ -3026,9 +3322,11 @@ fn bindgen_test_layout_sqlite3_index_info_sqlite3_index_orderby() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info_sqlite3_index_orderby>())).iColumn
-                as *const _ as usize
+        {
+            let uninit =
+                ::core::mem::MaybeUninit::<sqlite3_index_info_sqlite3_index_orderby>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iColumn) as usize - ptr as usize }
         },
         0usize,
         concat!(
@@ -3039,9 +3337,11 @@ fn bindgen_test_layout_sqlite3_index_info_sqlite3_index_orderby() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info_sqlite3_index_orderby>())).desc as *const _
-                as usize
+        {
+            let uninit =
+                ::core::mem::MaybeUninit::<sqlite3_index_info_sqlite3_index_orderby>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).desc) as usize - ptr as usize }
         },
         4usize,
         concat!(
@@ -3077,9 +3377,12 @@ fn bindgen_test_layout_sqlite3_index_info_sqlite3_index_constraint_usage() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info_sqlite3_index_constraint_usage>())).argvIndex
-                as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<
+                sqlite3_index_info_sqlite3_index_constraint_usage,
+            >::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).argvIndex) as usize - ptr as usize }
         },
         0usize,
         concat!(
@@ -3090,9 +3393,12 @@ fn bindgen_test_layout_sqlite3_index_info_sqlite3_index_constraint_usage() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info_sqlite3_index_constraint_usage>())).omit
-                as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<
+                sqlite3_index_info_sqlite3_index_constraint_usage,
+            >::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).omit) as usize - ptr as usize }
         },
         4usize,
         concat!(
@@ -3116,7 +3422,11 @@ fn bindgen_test_layout_sqlite3_index_info() {
         concat!("Alignment of ", stringify!(sqlite3_index_info))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_index_info>())).nConstraint as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).nConstraint) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -3126,7 +3436,11 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_index_info>())).aConstraint as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).aConstraint) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -3136,7 +3450,11 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_index_info>())).nOrderBy as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).nOrderBy) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -3146,7 +3464,11 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_index_info>())).aOrderBy as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).aOrderBy) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -3156,8 +3478,10 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info>())).aConstraintUsage as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).aConstraintUsage) as usize - ptr as usize }
         },
         32usize,
         concat!(
@@ -3168,7 +3492,11 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_index_info>())).idxNum as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).idxNum) as usize - ptr as usize }
+        },
         40usize,
         concat!(
             "Offset of field: ",
@@ -3178,7 +3506,11 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_index_info>())).idxStr as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).idxStr) as usize - ptr as usize }
+        },
         48usize,
         concat!(
             "Offset of field: ",
@@ -3188,8 +3520,10 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info>())).needToFreeIdxStr as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).needToFreeIdxStr) as usize - ptr as usize }
         },
         56usize,
         concat!(
@@ -3200,8 +3534,10 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info>())).orderByConsumed as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).orderByConsumed) as usize - ptr as usize }
         },
         60usize,
         concat!(
@@ -3212,8 +3548,10 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info>())).estimatedCost as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).estimatedCost) as usize - ptr as usize }
         },
         64usize,
         concat!(
@@ -3224,8 +3562,10 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_index_info>())).estimatedRows as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).estimatedRows) as usize - ptr as usize }
         },
         72usize,
         concat!(
@@ -3236,7 +3576,11 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_index_info>())).idxFlags as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).idxFlags) as usize - ptr as usize }
+        },
         80usize,
         concat!(
             "Offset of field: ",
@@ -3246,7 +3590,11 @@ fn bindgen_test_layout_sqlite3_index_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_index_info>())).colUsed as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_index_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).colUsed) as usize - ptr as usize }
+        },
         88usize,
         concat!(
             "Offset of field: ",
@@ -3299,7 +3647,11 @@ fn bindgen_test_layout_sqlite3_vtab() {
         concat!("Alignment of ", stringify!(sqlite3_vtab))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vtab>())).pModule as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vtab>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pModule) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -3309,7 +3661,11 @@ fn bindgen_test_layout_sqlite3_vtab() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vtab>())).nRef as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vtab>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).nRef) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -3319,7 +3675,11 @@ fn bindgen_test_layout_sqlite3_vtab() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vtab>())).zErrMsg as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vtab>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).zErrMsg) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -3347,7 +3707,11 @@ fn bindgen_test_layout_sqlite3_vtab_cursor() {
         concat!("Alignment of ", stringify!(sqlite3_vtab_cursor))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_vtab_cursor>())).pVtab as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_vtab_cursor>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pVtab) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -3472,8 +3836,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         concat!("Alignment of ", stringify!(sqlite3_mutex_methods))
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexInit as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexInit) as usize - ptr as usize }
         },
         0usize,
         concat!(
@@ -3484,8 +3850,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexEnd as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexEnd) as usize - ptr as usize }
         },
         8usize,
         concat!(
@@ -3496,8 +3864,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexAlloc as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexAlloc) as usize - ptr as usize }
         },
         16usize,
         concat!(
@@ -3508,8 +3878,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexFree as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexFree) as usize - ptr as usize }
         },
         24usize,
         concat!(
@@ -3520,8 +3892,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexEnter as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexEnter) as usize - ptr as usize }
         },
         32usize,
         concat!(
@@ -3532,8 +3906,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexTry as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexTry) as usize - ptr as usize }
         },
         40usize,
         concat!(
@@ -3544,8 +3920,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexLeave as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexLeave) as usize - ptr as usize }
         },
         48usize,
         concat!(
@@ -3556,8 +3934,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexHeld as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexHeld) as usize - ptr as usize }
         },
         56usize,
         concat!(
@@ -3568,8 +3948,10 @@ fn bindgen_test_layout_sqlite3_mutex_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_mutex_methods>())).xMutexNotheld as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_mutex_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xMutexNotheld) as usize - ptr as usize }
         },
         64usize,
         concat!(
@@ -3722,7 +4104,11 @@ fn bindgen_test_layout_sqlite3_pcache_page() {
         concat!("Alignment of ", stringify!(sqlite3_pcache_page))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_page>())).pBuf as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_page>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pBuf) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -3732,7 +4118,11 @@ fn bindgen_test_layout_sqlite3_pcache_page() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_page>())).pExtra as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_page>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pExtra) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -3805,8 +4195,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         concat!("Alignment of ", stringify!(sqlite3_pcache_methods2))
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).iVersion as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iVersion) as usize - ptr as usize }
         },
         0usize,
         concat!(
@@ -3817,7 +4209,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).pArg as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pArg) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -3827,7 +4223,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xInit as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xInit) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -3837,8 +4237,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xShutdown as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShutdown) as usize - ptr as usize }
         },
         24usize,
         concat!(
@@ -3849,8 +4251,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xCreate as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCreate) as usize - ptr as usize }
         },
         32usize,
         concat!(
@@ -3861,8 +4265,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xCachesize as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCachesize) as usize - ptr as usize }
         },
         40usize,
         concat!(
@@ -3873,8 +4279,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xPagecount as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xPagecount) as usize - ptr as usize }
         },
         48usize,
         concat!(
@@ -3885,7 +4293,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xFetch as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFetch) as usize - ptr as usize }
+        },
         56usize,
         concat!(
             "Offset of field: ",
@@ -3895,7 +4307,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xUnpin as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xUnpin) as usize - ptr as usize }
+        },
         64usize,
         concat!(
             "Offset of field: ",
@@ -3905,7 +4321,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xRekey as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRekey) as usize - ptr as usize }
+        },
         72usize,
         concat!(
             "Offset of field: ",
@@ -3915,8 +4335,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xTruncate as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xTruncate) as usize - ptr as usize }
         },
         80usize,
         concat!(
@@ -3927,8 +4349,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xDestroy as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDestroy) as usize - ptr as usize }
         },
         88usize,
         concat!(
@@ -3939,8 +4363,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods2>())).xShrink as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods2>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShrink) as usize - ptr as usize }
         },
         96usize,
         concat!(
@@ -4011,7 +4437,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         concat!("Alignment of ", stringify!(sqlite3_pcache_methods))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods>())).pArg as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pArg) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4021,7 +4451,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xInit as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xInit) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -4031,8 +4465,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xShutdown as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xShutdown) as usize - ptr as usize }
         },
         16usize,
         concat!(
@@ -4043,7 +4479,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xCreate as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCreate) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -4053,8 +4493,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xCachesize as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCachesize) as usize - ptr as usize }
         },
         32usize,
         concat!(
@@ -4065,8 +4507,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xPagecount as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xPagecount) as usize - ptr as usize }
         },
         40usize,
         concat!(
@@ -4077,7 +4521,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xFetch as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFetch) as usize - ptr as usize }
+        },
         48usize,
         concat!(
             "Offset of field: ",
@@ -4087,7 +4535,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xUnpin as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xUnpin) as usize - ptr as usize }
+        },
         56usize,
         concat!(
             "Offset of field: ",
@@ -4097,7 +4549,11 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xRekey as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRekey) as usize - ptr as usize }
+        },
         64usize,
         concat!(
             "Offset of field: ",
@@ -4107,8 +4563,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xTruncate as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xTruncate) as usize - ptr as usize }
         },
         72usize,
         concat!(
@@ -4119,8 +4577,10 @@ fn bindgen_test_layout_sqlite3_pcache_methods() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_pcache_methods>())).xDestroy as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_pcache_methods>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDestroy) as usize - ptr as usize }
         },
         80usize,
         concat!(
@@ -4286,7 +4746,11 @@ fn bindgen_test_layout_sqlite3_snapshot() {
         concat!("Alignment of ", stringify!(sqlite3_snapshot))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_snapshot>())).hidden as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_snapshot>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).hidden) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4381,8 +4845,10 @@ fn bindgen_test_layout_sqlite3_rtree_geometry() {
         concat!("Alignment of ", stringify!(sqlite3_rtree_geometry))
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_geometry>())).pContext as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_geometry>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pContext) as usize - ptr as usize }
         },
         0usize,
         concat!(
@@ -4393,7 +4859,11 @@ fn bindgen_test_layout_sqlite3_rtree_geometry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_rtree_geometry>())).nParam as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_geometry>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).nParam) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -4403,7 +4873,11 @@ fn bindgen_test_layout_sqlite3_rtree_geometry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_rtree_geometry>())).aParam as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_geometry>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).aParam) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -4413,7 +4887,11 @@ fn bindgen_test_layout_sqlite3_rtree_geometry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_rtree_geometry>())).pUser as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_geometry>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pUser) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -4423,8 +4901,10 @@ fn bindgen_test_layout_sqlite3_rtree_geometry() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_geometry>())).xDelUser as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_geometry>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDelUser) as usize - ptr as usize }
         },
         32usize,
         concat!(
@@ -4479,8 +4959,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         concat!("Alignment of ", stringify!(sqlite3_rtree_query_info))
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).pContext as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pContext) as usize - ptr as usize }
         },
         0usize,
         concat!(
@@ -4491,8 +4973,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).nParam as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).nParam) as usize - ptr as usize }
         },
         8usize,
         concat!(
@@ -4503,8 +4987,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).aParam as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).aParam) as usize - ptr as usize }
         },
         16usize,
         concat!(
@@ -4515,7 +5001,11 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).pUser as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).pUser) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -4525,8 +5015,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).xDelUser as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDelUser) as usize - ptr as usize }
         },
         32usize,
         concat!(
@@ -4537,8 +5029,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).aCoord as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).aCoord) as usize - ptr as usize }
         },
         40usize,
         concat!(
@@ -4549,8 +5043,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).anQueue as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).anQueue) as usize - ptr as usize }
         },
         48usize,
         concat!(
@@ -4561,8 +5057,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).nCoord as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).nCoord) as usize - ptr as usize }
         },
         56usize,
         concat!(
@@ -4573,8 +5071,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).iLevel as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iLevel) as usize - ptr as usize }
         },
         60usize,
         concat!(
@@ -4585,8 +5085,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).mxLevel as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).mxLevel) as usize - ptr as usize }
         },
         64usize,
         concat!(
@@ -4597,8 +5099,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).iRowid as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iRowid) as usize - ptr as usize }
         },
         72usize,
         concat!(
@@ -4609,8 +5113,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).rParentScore as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).rParentScore) as usize - ptr as usize }
         },
         80usize,
         concat!(
@@ -4621,8 +5127,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).eParentWithin as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).eParentWithin) as usize - ptr as usize }
         },
         88usize,
         concat!(
@@ -4633,8 +5141,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).eWithin as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).eWithin) as usize - ptr as usize }
         },
         92usize,
         concat!(
@@ -4645,8 +5155,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).rScore as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).rScore) as usize - ptr as usize }
         },
         96usize,
         concat!(
@@ -4657,8 +5169,10 @@ fn bindgen_test_layout_sqlite3_rtree_query_info() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_rtree_query_info>())).apSqlParam as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_rtree_query_info>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).apSqlParam) as usize - ptr as usize }
         },
         104usize,
         concat!(
@@ -4702,7 +5216,11 @@ fn bindgen_test_layout_Fts5PhraseIter() {
         concat!("Alignment of ", stringify!(Fts5PhraseIter))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5PhraseIter>())).a as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5PhraseIter>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).a) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4712,7 +5230,11 @@ fn bindgen_test_layout_Fts5PhraseIter() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5PhraseIter>())).b as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5PhraseIter>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).b) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -4875,7 +5397,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         concat!("Alignment of ", stringify!(Fts5ExtensionApi))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).iVersion as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iVersion) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -4885,7 +5411,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xUserData as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xUserData) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -4895,7 +5425,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xColumnCount as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xColumnCount) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -4905,7 +5439,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xRowCount as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRowCount) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -4915,8 +5453,10 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<Fts5ExtensionApi>())).xColumnTotalSize as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xColumnTotalSize) as usize - ptr as usize }
         },
         32usize,
         concat!(
@@ -4927,7 +5467,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xTokenize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xTokenize) as usize - ptr as usize }
+        },
         40usize,
         concat!(
             "Offset of field: ",
@@ -4937,7 +5481,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xPhraseCount as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xPhraseCount) as usize - ptr as usize }
+        },
         48usize,
         concat!(
             "Offset of field: ",
@@ -4947,7 +5495,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xPhraseSize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xPhraseSize) as usize - ptr as usize }
+        },
         56usize,
         concat!(
             "Offset of field: ",
@@ -4957,7 +5509,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xInstCount as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xInstCount) as usize - ptr as usize }
+        },
         64usize,
         concat!(
             "Offset of field: ",
@@ -4967,7 +5523,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xInst as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xInst) as usize - ptr as usize }
+        },
         72usize,
         concat!(
             "Offset of field: ",
@@ -4977,7 +5537,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xRowid as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xRowid) as usize - ptr as usize }
+        },
         80usize,
         concat!(
             "Offset of field: ",
@@ -4987,7 +5551,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xColumnText as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xColumnText) as usize - ptr as usize }
+        },
         88usize,
         concat!(
             "Offset of field: ",
@@ -4997,7 +5565,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xColumnSize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xColumnSize) as usize - ptr as usize }
+        },
         96usize,
         concat!(
             "Offset of field: ",
@@ -5007,7 +5579,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xQueryPhrase as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xQueryPhrase) as usize - ptr as usize }
+        },
         104usize,
         concat!(
             "Offset of field: ",
@@ -5017,7 +5593,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xSetAuxdata as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xSetAuxdata) as usize - ptr as usize }
+        },
         112usize,
         concat!(
             "Offset of field: ",
@@ -5027,7 +5607,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xGetAuxdata as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xGetAuxdata) as usize - ptr as usize }
+        },
         120usize,
         concat!(
             "Offset of field: ",
@@ -5037,7 +5621,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xPhraseFirst as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xPhraseFirst) as usize - ptr as usize }
+        },
         128usize,
         concat!(
             "Offset of field: ",
@@ -5047,7 +5635,11 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<Fts5ExtensionApi>())).xPhraseNext as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xPhraseNext) as usize - ptr as usize }
+        },
         136usize,
         concat!(
             "Offset of field: ",
@@ -5057,8 +5649,10 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<Fts5ExtensionApi>())).xPhraseFirstColumn as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xPhraseFirstColumn) as usize - ptr as usize }
         },
         144usize,
         concat!(
@@ -5069,8 +5663,10 @@ fn bindgen_test_layout_Fts5ExtensionApi() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<Fts5ExtensionApi>())).xPhraseNextColumn as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<Fts5ExtensionApi>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xPhraseNextColumn) as usize - ptr as usize }
         },
         152usize,
         concat!(
@@ -5131,7 +5727,11 @@ fn bindgen_test_layout_fts5_tokenizer() {
         concat!("Alignment of ", stringify!(fts5_tokenizer))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<fts5_tokenizer>())).xCreate as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<fts5_tokenizer>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCreate) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5141,7 +5741,11 @@ fn bindgen_test_layout_fts5_tokenizer() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<fts5_tokenizer>())).xDelete as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<fts5_tokenizer>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xDelete) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -5151,7 +5755,11 @@ fn bindgen_test_layout_fts5_tokenizer() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<fts5_tokenizer>())).xTokenize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<fts5_tokenizer>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xTokenize) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -5205,7 +5813,11 @@ fn bindgen_test_layout_fts5_api() {
         concat!("Alignment of ", stringify!(fts5_api))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<fts5_api>())).iVersion as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<fts5_api>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).iVersion) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -5215,7 +5827,11 @@ fn bindgen_test_layout_fts5_api() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<fts5_api>())).xCreateTokenizer as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<fts5_api>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCreateTokenizer) as usize - ptr as usize }
+        },
         8usize,
         concat!(
             "Offset of field: ",
@@ -5225,7 +5841,11 @@ fn bindgen_test_layout_fts5_api() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<fts5_api>())).xFindTokenizer as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<fts5_api>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xFindTokenizer) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -5235,7 +5855,11 @@ fn bindgen_test_layout_fts5_api() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<fts5_api>())).xCreateFunction as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<fts5_api>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xCreateFunction) as usize - ptr as usize }
+        },
         24usize,
         concat!(
             "Offset of field: ",
@@ -6645,8 +7269,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         concat!("Alignment of ", stringify!(sqlite3_api_routines))
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).aggregate_context as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).aggregate_context) as usize - ptr as usize }
         },
         0usize,
         concat!(
@@ -6657,8 +7283,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).aggregate_count as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).aggregate_count) as usize - ptr as usize }
         },
         8usize,
         concat!(
@@ -6669,7 +7297,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_blob as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_blob) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -6679,8 +7311,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_double as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_double) as usize - ptr as usize }
         },
         24usize,
         concat!(
@@ -6691,7 +7325,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_int as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_int) as usize - ptr as usize }
+        },
         32usize,
         concat!(
             "Offset of field: ",
@@ -6701,8 +7339,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_int64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_int64) as usize - ptr as usize }
         },
         40usize,
         concat!(
@@ -6713,7 +7353,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_null as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_null) as usize - ptr as usize }
+        },
         48usize,
         concat!(
             "Offset of field: ",
@@ -6723,9 +7367,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_parameter_count as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_parameter_count) as usize - ptr as usize }
         },
         56usize,
         concat!(
@@ -6736,9 +7381,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_parameter_index as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_parameter_index) as usize - ptr as usize }
         },
         64usize,
         concat!(
@@ -6749,9 +7395,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_parameter_name as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_parameter_name) as usize - ptr as usize }
         },
         72usize,
         concat!(
@@ -6762,7 +7409,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_text as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_text) as usize - ptr as usize }
+        },
         80usize,
         concat!(
             "Offset of field: ",
@@ -6772,8 +7423,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_text16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_text16) as usize - ptr as usize }
         },
         88usize,
         concat!(
@@ -6784,8 +7437,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_value as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_value) as usize - ptr as usize }
         },
         96usize,
         concat!(
@@ -6796,8 +7451,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).busy_handler as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).busy_handler) as usize - ptr as usize }
         },
         104usize,
         concat!(
@@ -6808,8 +7465,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).busy_timeout as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).busy_timeout) as usize - ptr as usize }
         },
         112usize,
         concat!(
@@ -6820,7 +7479,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).changes as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).changes) as usize - ptr as usize }
+        },
         120usize,
         concat!(
             "Offset of field: ",
@@ -6830,7 +7493,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).close as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).close) as usize - ptr as usize }
+        },
         128usize,
         concat!(
             "Offset of field: ",
@@ -6840,8 +7507,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).collation_needed as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).collation_needed) as usize - ptr as usize }
         },
         136usize,
         concat!(
@@ -6852,9 +7521,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).collation_needed16 as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).collation_needed16) as usize - ptr as usize }
         },
         144usize,
         concat!(
@@ -6865,8 +7535,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_blob as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_blob) as usize - ptr as usize }
         },
         152usize,
         concat!(
@@ -6877,8 +7549,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_bytes as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_bytes) as usize - ptr as usize }
         },
         160usize,
         concat!(
@@ -6889,8 +7563,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_bytes16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_bytes16) as usize - ptr as usize }
         },
         168usize,
         concat!(
@@ -6901,8 +7577,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_count as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_count) as usize - ptr as usize }
         },
         176usize,
         concat!(
@@ -6913,9 +7591,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_database_name as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_database_name) as usize - ptr as usize }
         },
         184usize,
         concat!(
@@ -6926,9 +7605,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_database_name16 as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_database_name16) as usize - ptr as usize }
         },
         192usize,
         concat!(
@@ -6939,8 +7619,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_decltype as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_decltype) as usize - ptr as usize }
         },
         200usize,
         concat!(
@@ -6951,8 +7633,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_decltype16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_decltype16) as usize - ptr as usize }
         },
         208usize,
         concat!(
@@ -6963,8 +7647,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_double as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_double) as usize - ptr as usize }
         },
         216usize,
         concat!(
@@ -6975,8 +7661,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_int as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_int) as usize - ptr as usize }
         },
         224usize,
         concat!(
@@ -6987,8 +7675,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_int64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_int64) as usize - ptr as usize }
         },
         232usize,
         concat!(
@@ -6999,8 +7689,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_name as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_name) as usize - ptr as usize }
         },
         240usize,
         concat!(
@@ -7011,8 +7703,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_name16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_name16) as usize - ptr as usize }
         },
         248usize,
         concat!(
@@ -7023,9 +7717,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_origin_name as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_origin_name) as usize - ptr as usize }
         },
         256usize,
         concat!(
@@ -7036,9 +7731,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_origin_name16 as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_origin_name16) as usize - ptr as usize }
         },
         264usize,
         concat!(
@@ -7049,8 +7745,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_table_name as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_table_name) as usize - ptr as usize }
         },
         272usize,
         concat!(
@@ -7061,9 +7759,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_table_name16 as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_table_name16) as usize - ptr as usize }
         },
         280usize,
         concat!(
@@ -7074,8 +7773,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_text as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_text) as usize - ptr as usize }
         },
         288usize,
         concat!(
@@ -7086,8 +7787,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_text16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_text16) as usize - ptr as usize }
         },
         296usize,
         concat!(
@@ -7098,8 +7801,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_type as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_type) as usize - ptr as usize }
         },
         304usize,
         concat!(
@@ -7110,8 +7815,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).column_value as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).column_value) as usize - ptr as usize }
         },
         312usize,
         concat!(
@@ -7122,8 +7829,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).commit_hook as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).commit_hook) as usize - ptr as usize }
         },
         320usize,
         concat!(
@@ -7134,7 +7843,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).complete as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).complete) as usize - ptr as usize }
+        },
         328usize,
         concat!(
             "Offset of field: ",
@@ -7144,8 +7857,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).complete16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).complete16) as usize - ptr as usize }
         },
         336usize,
         concat!(
@@ -7156,8 +7871,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_collation as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_collation) as usize - ptr as usize }
         },
         344usize,
         concat!(
@@ -7168,9 +7885,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_collation16 as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_collation16) as usize - ptr as usize }
         },
         352usize,
         concat!(
@@ -7181,8 +7899,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_function as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_function) as usize - ptr as usize }
         },
         360usize,
         concat!(
@@ -7193,8 +7913,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_function16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_function16) as usize - ptr as usize }
         },
         368usize,
         concat!(
@@ -7205,8 +7927,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_module as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_module) as usize - ptr as usize }
         },
         376usize,
         concat!(
@@ -7217,8 +7941,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).data_count as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).data_count) as usize - ptr as usize }
         },
         384usize,
         concat!(
@@ -7229,7 +7955,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).db_handle as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).db_handle) as usize - ptr as usize }
+        },
         392usize,
         concat!(
             "Offset of field: ",
@@ -7239,8 +7969,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).declare_vtab as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).declare_vtab) as usize - ptr as usize }
         },
         400usize,
         concat!(
@@ -7251,9 +7983,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).enable_shared_cache as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).enable_shared_cache) as usize - ptr as usize }
         },
         408usize,
         concat!(
@@ -7264,7 +7997,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).errcode as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).errcode) as usize - ptr as usize }
+        },
         416usize,
         concat!(
             "Offset of field: ",
@@ -7274,7 +8011,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).errmsg as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).errmsg) as usize - ptr as usize }
+        },
         424usize,
         concat!(
             "Offset of field: ",
@@ -7284,7 +8025,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).errmsg16 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).errmsg16) as usize - ptr as usize }
+        },
         432usize,
         concat!(
             "Offset of field: ",
@@ -7294,7 +8039,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).exec as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).exec) as usize - ptr as usize }
+        },
         440usize,
         concat!(
             "Offset of field: ",
@@ -7304,7 +8053,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).expired as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).expired) as usize - ptr as usize }
+        },
         448usize,
         concat!(
             "Offset of field: ",
@@ -7314,7 +8067,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).finalize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).finalize) as usize - ptr as usize }
+        },
         456usize,
         concat!(
             "Offset of field: ",
@@ -7324,7 +8081,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).free as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).free) as usize - ptr as usize }
+        },
         464usize,
         concat!(
             "Offset of field: ",
@@ -7334,8 +8095,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).free_table as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).free_table) as usize - ptr as usize }
         },
         472usize,
         concat!(
@@ -7346,8 +8109,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).get_autocommit as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).get_autocommit) as usize - ptr as usize }
         },
         480usize,
         concat!(
@@ -7358,8 +8123,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).get_auxdata as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).get_auxdata) as usize - ptr as usize }
         },
         488usize,
         concat!(
@@ -7370,7 +8137,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).get_table as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).get_table) as usize - ptr as usize }
+        },
         496usize,
         concat!(
             "Offset of field: ",
@@ -7380,8 +8151,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).global_recover as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).global_recover) as usize - ptr as usize }
         },
         504usize,
         concat!(
@@ -7392,8 +8165,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).interruptx as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).interruptx) as usize - ptr as usize }
         },
         512usize,
         concat!(
@@ -7404,8 +8179,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).last_insert_rowid as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).last_insert_rowid) as usize - ptr as usize }
         },
         520usize,
         concat!(
@@ -7416,8 +8193,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).libversion as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).libversion) as usize - ptr as usize }
         },
         528usize,
         concat!(
@@ -7428,8 +8207,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).libversion_number as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).libversion_number) as usize - ptr as usize }
         },
         536usize,
         concat!(
@@ -7440,7 +8221,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).malloc as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).malloc) as usize - ptr as usize }
+        },
         544usize,
         concat!(
             "Offset of field: ",
@@ -7450,7 +8235,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).mprintf as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).mprintf) as usize - ptr as usize }
+        },
         552usize,
         concat!(
             "Offset of field: ",
@@ -7460,7 +8249,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).open as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).open) as usize - ptr as usize }
+        },
         560usize,
         concat!(
             "Offset of field: ",
@@ -7470,7 +8263,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).open16 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).open16) as usize - ptr as usize }
+        },
         568usize,
         concat!(
             "Offset of field: ",
@@ -7480,7 +8277,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).prepare as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).prepare) as usize - ptr as usize }
+        },
         576usize,
         concat!(
             "Offset of field: ",
@@ -7490,7 +8291,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).prepare16 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).prepare16) as usize - ptr as usize }
+        },
         584usize,
         concat!(
             "Offset of field: ",
@@ -7500,7 +8305,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).profile as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).profile) as usize - ptr as usize }
+        },
         592usize,
         concat!(
             "Offset of field: ",
@@ -7510,8 +8319,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).progress_handler as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).progress_handler) as usize - ptr as usize }
         },
         600usize,
         concat!(
@@ -7522,7 +8333,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).realloc as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).realloc) as usize - ptr as usize }
+        },
         608usize,
         concat!(
             "Offset of field: ",
@@ -7532,7 +8347,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).reset as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).reset) as usize - ptr as usize }
+        },
         616usize,
         concat!(
             "Offset of field: ",
@@ -7542,8 +8361,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_blob as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_blob) as usize - ptr as usize }
         },
         624usize,
         concat!(
@@ -7554,8 +8375,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_double as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_double) as usize - ptr as usize }
         },
         632usize,
         concat!(
@@ -7566,8 +8389,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_error as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_error) as usize - ptr as usize }
         },
         640usize,
         concat!(
@@ -7578,8 +8403,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_error16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_error16) as usize - ptr as usize }
         },
         648usize,
         concat!(
@@ -7590,8 +8417,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_int as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_int) as usize - ptr as usize }
         },
         656usize,
         concat!(
@@ -7602,8 +8431,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_int64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_int64) as usize - ptr as usize }
         },
         664usize,
         concat!(
@@ -7614,8 +8445,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_null as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_null) as usize - ptr as usize }
         },
         672usize,
         concat!(
@@ -7626,8 +8459,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_text as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_text) as usize - ptr as usize }
         },
         680usize,
         concat!(
@@ -7638,8 +8473,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_text16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_text16) as usize - ptr as usize }
         },
         688usize,
         concat!(
@@ -7650,8 +8487,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_text16be as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_text16be) as usize - ptr as usize }
         },
         696usize,
         concat!(
@@ -7662,8 +8501,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_text16le as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_text16le) as usize - ptr as usize }
         },
         704usize,
         concat!(
@@ -7674,8 +8515,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_value as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_value) as usize - ptr as usize }
         },
         712usize,
         concat!(
@@ -7686,8 +8529,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).rollback_hook as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).rollback_hook) as usize - ptr as usize }
         },
         720usize,
         concat!(
@@ -7698,8 +8543,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).set_authorizer as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).set_authorizer) as usize - ptr as usize }
         },
         728usize,
         concat!(
@@ -7710,8 +8557,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).set_auxdata as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).set_auxdata) as usize - ptr as usize }
         },
         736usize,
         concat!(
@@ -7722,7 +8571,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).xsnprintf as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xsnprintf) as usize - ptr as usize }
+        },
         744usize,
         concat!(
             "Offset of field: ",
@@ -7732,7 +8585,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).step as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).step) as usize - ptr as usize }
+        },
         752usize,
         concat!(
             "Offset of field: ",
@@ -7742,9 +8599,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).table_column_metadata as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).table_column_metadata) as usize - ptr as usize }
         },
         760usize,
         concat!(
@@ -7755,8 +8613,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).thread_cleanup as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).thread_cleanup) as usize - ptr as usize }
         },
         768usize,
         concat!(
@@ -7767,8 +8627,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).total_changes as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).total_changes) as usize - ptr as usize }
         },
         776usize,
         concat!(
@@ -7779,7 +8641,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).trace as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).trace) as usize - ptr as usize }
+        },
         784usize,
         concat!(
             "Offset of field: ",
@@ -7789,8 +8655,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).transfer_bindings as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).transfer_bindings) as usize - ptr as usize }
         },
         792usize,
         concat!(
@@ -7801,8 +8669,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).update_hook as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).update_hook) as usize - ptr as usize }
         },
         800usize,
         concat!(
@@ -7813,7 +8683,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).user_data as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).user_data) as usize - ptr as usize }
+        },
         808usize,
         concat!(
             "Offset of field: ",
@@ -7823,8 +8697,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_blob as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_blob) as usize - ptr as usize }
         },
         816usize,
         concat!(
@@ -7835,8 +8711,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_bytes as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_bytes) as usize - ptr as usize }
         },
         824usize,
         concat!(
@@ -7847,8 +8725,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_bytes16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_bytes16) as usize - ptr as usize }
         },
         832usize,
         concat!(
@@ -7859,8 +8739,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_double as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_double) as usize - ptr as usize }
         },
         840usize,
         concat!(
@@ -7871,7 +8753,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).value_int as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_int) as usize - ptr as usize }
+        },
         848usize,
         concat!(
             "Offset of field: ",
@@ -7881,8 +8767,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_int64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_int64) as usize - ptr as usize }
         },
         856usize,
         concat!(
@@ -7893,9 +8781,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_numeric_type as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_numeric_type) as usize - ptr as usize }
         },
         864usize,
         concat!(
@@ -7906,8 +8795,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_text as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_text) as usize - ptr as usize }
         },
         872usize,
         concat!(
@@ -7918,8 +8809,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_text16 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_text16) as usize - ptr as usize }
         },
         880usize,
         concat!(
@@ -7930,8 +8823,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_text16be as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_text16be) as usize - ptr as usize }
         },
         888usize,
         concat!(
@@ -7942,8 +8837,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_text16le as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_text16le) as usize - ptr as usize }
         },
         896usize,
         concat!(
@@ -7954,8 +8851,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_type as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_type) as usize - ptr as usize }
         },
         904usize,
         concat!(
@@ -7966,7 +8865,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).vmprintf as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).vmprintf) as usize - ptr as usize }
+        },
         912usize,
         concat!(
             "Offset of field: ",
@@ -7976,8 +8879,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).overload_function as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).overload_function) as usize - ptr as usize }
         },
         920usize,
         concat!(
@@ -7988,8 +8893,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).prepare_v2 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).prepare_v2) as usize - ptr as usize }
         },
         928usize,
         concat!(
@@ -8000,8 +8907,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).prepare16_v2 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).prepare16_v2) as usize - ptr as usize }
         },
         936usize,
         concat!(
@@ -8012,8 +8921,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).clear_bindings as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).clear_bindings) as usize - ptr as usize }
         },
         944usize,
         concat!(
@@ -8024,8 +8935,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_module_v2 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_module_v2) as usize - ptr as usize }
         },
         952usize,
         concat!(
@@ -8036,8 +8949,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_zeroblob as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_zeroblob) as usize - ptr as usize }
         },
         960usize,
         concat!(
@@ -8048,8 +8963,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).blob_bytes as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).blob_bytes) as usize - ptr as usize }
         },
         968usize,
         concat!(
@@ -8060,8 +8977,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).blob_close as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).blob_close) as usize - ptr as usize }
         },
         976usize,
         concat!(
@@ -8072,7 +8991,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).blob_open as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).blob_open) as usize - ptr as usize }
+        },
         984usize,
         concat!(
             "Offset of field: ",
@@ -8082,7 +9005,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).blob_read as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).blob_read) as usize - ptr as usize }
+        },
         992usize,
         concat!(
             "Offset of field: ",
@@ -8092,8 +9019,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).blob_write as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).blob_write) as usize - ptr as usize }
         },
         1000usize,
         concat!(
@@ -8104,9 +9033,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_collation_v2 as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_collation_v2) as usize - ptr as usize }
         },
         1008usize,
         concat!(
@@ -8117,8 +9047,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).file_control as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).file_control) as usize - ptr as usize }
         },
         1016usize,
         concat!(
@@ -8129,8 +9061,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).memory_highwater as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).memory_highwater) as usize - ptr as usize }
         },
         1024usize,
         concat!(
@@ -8141,8 +9075,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).memory_used as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).memory_used) as usize - ptr as usize }
         },
         1032usize,
         concat!(
@@ -8153,8 +9089,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).mutex_alloc as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).mutex_alloc) as usize - ptr as usize }
         },
         1040usize,
         concat!(
@@ -8165,8 +9103,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).mutex_enter as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).mutex_enter) as usize - ptr as usize }
         },
         1048usize,
         concat!(
@@ -8177,8 +9117,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).mutex_free as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).mutex_free) as usize - ptr as usize }
         },
         1056usize,
         concat!(
@@ -8189,8 +9131,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).mutex_leave as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).mutex_leave) as usize - ptr as usize }
         },
         1064usize,
         concat!(
@@ -8201,7 +9145,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).mutex_try as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).mutex_try) as usize - ptr as usize }
+        },
         1072usize,
         concat!(
             "Offset of field: ",
@@ -8211,7 +9159,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).open_v2 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).open_v2) as usize - ptr as usize }
+        },
         1080usize,
         concat!(
             "Offset of field: ",
@@ -8221,8 +9173,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).release_memory as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).release_memory) as usize - ptr as usize }
         },
         1088usize,
         concat!(
@@ -8233,9 +9187,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_error_nomem as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_error_nomem) as usize - ptr as usize }
         },
         1096usize,
         concat!(
@@ -8246,9 +9201,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_error_toobig as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_error_toobig) as usize - ptr as usize }
         },
         1104usize,
         concat!(
@@ -8259,7 +9215,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).sleep as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).sleep) as usize - ptr as usize }
+        },
         1112usize,
         concat!(
             "Offset of field: ",
@@ -8269,8 +9229,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).soft_heap_limit as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).soft_heap_limit) as usize - ptr as usize }
         },
         1120usize,
         concat!(
@@ -8281,7 +9243,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).vfs_find as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).vfs_find) as usize - ptr as usize }
+        },
         1128usize,
         concat!(
             "Offset of field: ",
@@ -8291,8 +9257,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).vfs_register as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).vfs_register) as usize - ptr as usize }
         },
         1136usize,
         concat!(
@@ -8303,8 +9271,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).vfs_unregister as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).vfs_unregister) as usize - ptr as usize }
         },
         1144usize,
         concat!(
@@ -8315,8 +9285,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).xthreadsafe as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xthreadsafe) as usize - ptr as usize }
         },
         1152usize,
         concat!(
@@ -8327,8 +9299,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_zeroblob as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_zeroblob) as usize - ptr as usize }
         },
         1160usize,
         concat!(
@@ -8339,8 +9313,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_error_code as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_error_code) as usize - ptr as usize }
         },
         1168usize,
         concat!(
@@ -8351,8 +9327,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).test_control as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).test_control) as usize - ptr as usize }
         },
         1176usize,
         concat!(
@@ -8363,8 +9341,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).randomness as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).randomness) as usize - ptr as usize }
         },
         1184usize,
         concat!(
@@ -8375,8 +9355,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).context_db_handle as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).context_db_handle) as usize - ptr as usize }
         },
         1192usize,
         concat!(
@@ -8387,9 +9369,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).extended_result_codes as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).extended_result_codes) as usize - ptr as usize }
         },
         1200usize,
         concat!(
@@ -8400,7 +9383,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).limit as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).limit) as usize - ptr as usize }
+        },
         1208usize,
         concat!(
             "Offset of field: ",
@@ -8410,7 +9397,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).next_stmt as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).next_stmt) as usize - ptr as usize }
+        },
         1216usize,
         concat!(
             "Offset of field: ",
@@ -8420,7 +9411,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).sql as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).sql) as usize - ptr as usize }
+        },
         1224usize,
         concat!(
             "Offset of field: ",
@@ -8430,7 +9425,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).status as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).status) as usize - ptr as usize }
+        },
         1232usize,
         concat!(
             "Offset of field: ",
@@ -8440,8 +9439,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).backup_finish as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).backup_finish) as usize - ptr as usize }
         },
         1240usize,
         concat!(
@@ -8452,8 +9453,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).backup_init as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).backup_init) as usize - ptr as usize }
         },
         1248usize,
         concat!(
@@ -8464,8 +9467,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).backup_pagecount as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).backup_pagecount) as usize - ptr as usize }
         },
         1256usize,
         concat!(
@@ -8476,8 +9481,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).backup_remaining as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).backup_remaining) as usize - ptr as usize }
         },
         1264usize,
         concat!(
@@ -8488,8 +9495,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).backup_step as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).backup_step) as usize - ptr as usize }
         },
         1272usize,
         concat!(
@@ -8500,8 +9509,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).compileoption_get as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).compileoption_get) as usize - ptr as usize }
         },
         1280usize,
         concat!(
@@ -8512,9 +9523,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).compileoption_used as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).compileoption_used) as usize - ptr as usize }
         },
         1288usize,
         concat!(
@@ -8525,9 +9537,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_function_v2 as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_function_v2) as usize - ptr as usize }
         },
         1296usize,
         concat!(
@@ -8538,7 +9551,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).db_config as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).db_config) as usize - ptr as usize }
+        },
         1304usize,
         concat!(
             "Offset of field: ",
@@ -8548,7 +9565,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).db_mutex as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).db_mutex) as usize - ptr as usize }
+        },
         1312usize,
         concat!(
             "Offset of field: ",
@@ -8558,7 +9579,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).db_status as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).db_status) as usize - ptr as usize }
+        },
         1320usize,
         concat!(
             "Offset of field: ",
@@ -8568,8 +9593,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).extended_errcode as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).extended_errcode) as usize - ptr as usize }
         },
         1328usize,
         concat!(
@@ -8580,7 +9607,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).log as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).log) as usize - ptr as usize }
+        },
         1336usize,
         concat!(
             "Offset of field: ",
@@ -8590,8 +9621,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).soft_heap_limit64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).soft_heap_limit64) as usize - ptr as usize }
         },
         1344usize,
         concat!(
@@ -8602,7 +9635,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).sourceid as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).sourceid) as usize - ptr as usize }
+        },
         1352usize,
         concat!(
             "Offset of field: ",
@@ -8612,8 +9649,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).stmt_status as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).stmt_status) as usize - ptr as usize }
         },
         1360usize,
         concat!(
@@ -8624,7 +9663,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).strnicmp as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).strnicmp) as usize - ptr as usize }
+        },
         1368usize,
         concat!(
             "Offset of field: ",
@@ -8634,8 +9677,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).unlock_notify as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).unlock_notify) as usize - ptr as usize }
         },
         1376usize,
         concat!(
@@ -8646,9 +9691,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).wal_autocheckpoint as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).wal_autocheckpoint) as usize - ptr as usize }
         },
         1384usize,
         concat!(
@@ -8659,8 +9705,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).wal_checkpoint as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).wal_checkpoint) as usize - ptr as usize }
         },
         1392usize,
         concat!(
@@ -8671,7 +9719,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).wal_hook as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).wal_hook) as usize - ptr as usize }
+        },
         1400usize,
         concat!(
             "Offset of field: ",
@@ -8681,8 +9733,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).blob_reopen as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).blob_reopen) as usize - ptr as usize }
         },
         1408usize,
         concat!(
@@ -8693,8 +9747,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).vtab_config as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).vtab_config) as usize - ptr as usize }
         },
         1416usize,
         concat!(
@@ -8705,8 +9761,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).vtab_on_conflict as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).vtab_on_conflict) as usize - ptr as usize }
         },
         1424usize,
         concat!(
@@ -8717,7 +9775,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).close_v2 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).close_v2) as usize - ptr as usize }
+        },
         1432usize,
         concat!(
             "Offset of field: ",
@@ -8727,8 +9789,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).db_filename as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).db_filename) as usize - ptr as usize }
         },
         1440usize,
         concat!(
@@ -8739,8 +9803,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).db_readonly as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).db_readonly) as usize - ptr as usize }
         },
         1448usize,
         concat!(
@@ -8751,8 +9817,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).db_release_memory as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).db_release_memory) as usize - ptr as usize }
         },
         1456usize,
         concat!(
@@ -8763,7 +9831,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).errstr as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).errstr) as usize - ptr as usize }
+        },
         1464usize,
         concat!(
             "Offset of field: ",
@@ -8773,7 +9845,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).stmt_busy as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).stmt_busy) as usize - ptr as usize }
+        },
         1472usize,
         concat!(
             "Offset of field: ",
@@ -8783,8 +9859,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).stmt_readonly as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).stmt_readonly) as usize - ptr as usize }
         },
         1480usize,
         concat!(
@@ -8795,7 +9873,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).stricmp as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).stricmp) as usize - ptr as usize }
+        },
         1488usize,
         concat!(
             "Offset of field: ",
@@ -8805,8 +9887,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).uri_boolean as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).uri_boolean) as usize - ptr as usize }
         },
         1496usize,
         concat!(
@@ -8817,7 +9901,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).uri_int64 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).uri_int64) as usize - ptr as usize }
+        },
         1504usize,
         concat!(
             "Offset of field: ",
@@ -8827,8 +9915,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).uri_parameter as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).uri_parameter) as usize - ptr as usize }
         },
         1512usize,
         concat!(
@@ -8839,8 +9929,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).xvsnprintf as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).xvsnprintf) as usize - ptr as usize }
         },
         1520usize,
         concat!(
@@ -8851,8 +9943,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).wal_checkpoint_v2 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).wal_checkpoint_v2) as usize - ptr as usize }
         },
         1528usize,
         concat!(
@@ -8863,8 +9957,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).auto_extension as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).auto_extension) as usize - ptr as usize }
         },
         1536usize,
         concat!(
@@ -8875,8 +9971,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_blob64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_blob64) as usize - ptr as usize }
         },
         1544usize,
         concat!(
@@ -8887,8 +9985,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_text64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_text64) as usize - ptr as usize }
         },
         1552usize,
         concat!(
@@ -8899,9 +9999,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).cancel_auto_extension as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).cancel_auto_extension) as usize - ptr as usize }
         },
         1560usize,
         concat!(
@@ -8912,8 +10013,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).load_extension as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).load_extension) as usize - ptr as usize }
         },
         1568usize,
         concat!(
@@ -8924,7 +10027,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).malloc64 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).malloc64) as usize - ptr as usize }
+        },
         1576usize,
         concat!(
             "Offset of field: ",
@@ -8934,7 +10041,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).msize as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).msize) as usize - ptr as usize }
+        },
         1584usize,
         concat!(
             "Offset of field: ",
@@ -8944,7 +10055,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).realloc64 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).realloc64) as usize - ptr as usize }
+        },
         1592usize,
         concat!(
             "Offset of field: ",
@@ -8954,9 +10069,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).reset_auto_extension as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).reset_auto_extension) as usize - ptr as usize }
         },
         1600usize,
         concat!(
@@ -8967,8 +10083,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_blob64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_blob64) as usize - ptr as usize }
         },
         1608usize,
         concat!(
@@ -8979,8 +10097,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_text64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_text64) as usize - ptr as usize }
         },
         1616usize,
         concat!(
@@ -8991,7 +10111,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).strglob as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).strglob) as usize - ptr as usize }
+        },
         1624usize,
         concat!(
             "Offset of field: ",
@@ -9001,7 +10125,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).value_dup as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_dup) as usize - ptr as usize }
+        },
         1632usize,
         concat!(
             "Offset of field: ",
@@ -9011,8 +10139,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_free as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_free) as usize - ptr as usize }
         },
         1640usize,
         concat!(
@@ -9023,8 +10153,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_zeroblob64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_zeroblob64) as usize - ptr as usize }
         },
         1648usize,
         concat!(
@@ -9035,8 +10167,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_zeroblob64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_zeroblob64) as usize - ptr as usize }
         },
         1656usize,
         concat!(
@@ -9047,8 +10181,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_subtype as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_subtype) as usize - ptr as usize }
         },
         1664usize,
         concat!(
@@ -9059,8 +10195,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_subtype as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_subtype) as usize - ptr as usize }
         },
         1672usize,
         concat!(
@@ -9071,7 +10209,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).status64 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).status64) as usize - ptr as usize }
+        },
         1680usize,
         concat!(
             "Offset of field: ",
@@ -9081,7 +10223,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).strlike as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).strlike) as usize - ptr as usize }
+        },
         1688usize,
         concat!(
             "Offset of field: ",
@@ -9091,8 +10237,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).db_cacheflush as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).db_cacheflush) as usize - ptr as usize }
         },
         1696usize,
         concat!(
@@ -9103,8 +10251,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).system_errno as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).system_errno) as usize - ptr as usize }
         },
         1704usize,
         concat!(
@@ -9115,7 +10265,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).trace_v2 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).trace_v2) as usize - ptr as usize }
+        },
         1712usize,
         concat!(
             "Offset of field: ",
@@ -9125,8 +10279,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).expanded_sql as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).expanded_sql) as usize - ptr as usize }
         },
         1720usize,
         concat!(
@@ -9137,9 +10293,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).set_last_insert_rowid as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).set_last_insert_rowid) as usize - ptr as usize }
         },
         1728usize,
         concat!(
@@ -9150,8 +10307,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).prepare_v3 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).prepare_v3) as usize - ptr as usize }
         },
         1736usize,
         concat!(
@@ -9162,8 +10321,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).prepare16_v3 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).prepare16_v3) as usize - ptr as usize }
         },
         1744usize,
         concat!(
@@ -9174,8 +10335,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).bind_pointer as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).bind_pointer) as usize - ptr as usize }
         },
         1752usize,
         concat!(
@@ -9186,8 +10349,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).result_pointer as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).result_pointer) as usize - ptr as usize }
         },
         1760usize,
         concat!(
@@ -9198,8 +10363,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_pointer as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_pointer) as usize - ptr as usize }
         },
         1768usize,
         concat!(
@@ -9210,8 +10377,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).vtab_nochange as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).vtab_nochange) as usize - ptr as usize }
         },
         1776usize,
         concat!(
@@ -9222,8 +10391,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_nochange as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_nochange) as usize - ptr as usize }
         },
         1784usize,
         concat!(
@@ -9234,8 +10405,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).vtab_collation as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).vtab_collation) as usize - ptr as usize }
         },
         1792usize,
         concat!(
@@ -9246,8 +10419,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).keyword_count as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).keyword_count) as usize - ptr as usize }
         },
         1800usize,
         concat!(
@@ -9258,8 +10433,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).keyword_name as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).keyword_name) as usize - ptr as usize }
         },
         1808usize,
         concat!(
@@ -9270,8 +10447,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).keyword_check as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).keyword_check) as usize - ptr as usize }
         },
         1816usize,
         concat!(
@@ -9282,7 +10461,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).str_new as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_new) as usize - ptr as usize }
+        },
         1824usize,
         concat!(
             "Offset of field: ",
@@ -9292,8 +10475,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).str_finish as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_finish) as usize - ptr as usize }
         },
         1832usize,
         concat!(
@@ -9304,8 +10489,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).str_appendf as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_appendf) as usize - ptr as usize }
         },
         1840usize,
         concat!(
@@ -9316,8 +10503,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).str_vappendf as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_vappendf) as usize - ptr as usize }
         },
         1848usize,
         concat!(
@@ -9328,8 +10517,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).str_append as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_append) as usize - ptr as usize }
         },
         1856usize,
         concat!(
@@ -9340,8 +10531,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).str_appendall as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_appendall) as usize - ptr as usize }
         },
         1864usize,
         concat!(
@@ -9352,8 +10545,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).str_appendchar as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_appendchar) as usize - ptr as usize }
         },
         1872usize,
         concat!(
@@ -9364,7 +10559,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).str_reset as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_reset) as usize - ptr as usize }
+        },
         1880usize,
         concat!(
             "Offset of field: ",
@@ -9374,8 +10573,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).str_errcode as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_errcode) as usize - ptr as usize }
         },
         1888usize,
         concat!(
@@ -9386,8 +10587,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).str_length as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_length) as usize - ptr as usize }
         },
         1896usize,
         concat!(
@@ -9398,7 +10601,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).str_value as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).str_value) as usize - ptr as usize }
+        },
         1904usize,
         concat!(
             "Offset of field: ",
@@ -9408,9 +10615,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_window_function as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_window_function) as usize - ptr as usize }
         },
         1912usize,
         concat!(
@@ -9421,8 +10629,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).normalized_sql as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).normalized_sql) as usize - ptr as usize }
         },
         1920usize,
         concat!(
@@ -9433,8 +10643,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).stmt_isexplain as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).stmt_isexplain) as usize - ptr as usize }
         },
         1928usize,
         concat!(
@@ -9445,8 +10657,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).value_frombind as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).value_frombind) as usize - ptr as usize }
         },
         1936usize,
         concat!(
@@ -9457,8 +10671,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).drop_modules as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).drop_modules) as usize - ptr as usize }
         },
         1944usize,
         concat!(
@@ -9469,8 +10685,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).hard_heap_limit64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).hard_heap_limit64) as usize - ptr as usize }
         },
         1952usize,
         concat!(
@@ -9481,7 +10699,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).uri_key as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).uri_key) as usize - ptr as usize }
+        },
         1960usize,
         concat!(
             "Offset of field: ",
@@ -9491,8 +10713,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).filename_database as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).filename_database) as usize - ptr as usize }
         },
         1968usize,
         concat!(
@@ -9503,8 +10727,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).filename_journal as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).filename_journal) as usize - ptr as usize }
         },
         1976usize,
         concat!(
@@ -9515,8 +10741,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).filename_wal as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).filename_wal) as usize - ptr as usize }
         },
         1984usize,
         concat!(
@@ -9527,8 +10755,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).create_filename as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).create_filename) as usize - ptr as usize }
         },
         1992usize,
         concat!(
@@ -9539,8 +10769,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).free_filename as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).free_filename) as usize - ptr as usize }
         },
         2000usize,
         concat!(
@@ -9551,9 +10783,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).database_file_object as *const _
-                as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).database_file_object) as usize - ptr as usize }
         },
         2008usize,
         concat!(
@@ -9564,7 +10797,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).txn_state as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).txn_state) as usize - ptr as usize }
+        },
         2016usize,
         concat!(
             "Offset of field: ",
@@ -9574,7 +10811,11 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<sqlite3_api_routines>())).changes64 as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).changes64) as usize - ptr as usize }
+        },
         2024usize,
         concat!(
             "Offset of field: ",
@@ -9584,8 +10825,10 @@ fn bindgen_test_layout_sqlite3_api_routines() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<sqlite3_api_routines>())).total_changes64 as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<sqlite3_api_routines>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).total_changes64) as usize - ptr as usize }
         },
         2032usize,
         concat!(
@@ -9625,7 +10868,11 @@ fn bindgen_test_layout___va_list_tag() {
         concat!("Alignment of ", stringify!(__va_list_tag))
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<__va_list_tag>())).gp_offset as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<__va_list_tag>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).gp_offset) as usize - ptr as usize }
+        },
         0usize,
         concat!(
             "Offset of field: ",
@@ -9635,7 +10882,11 @@ fn bindgen_test_layout___va_list_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<__va_list_tag>())).fp_offset as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<__va_list_tag>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).fp_offset) as usize - ptr as usize }
+        },
         4usize,
         concat!(
             "Offset of field: ",
@@ -9645,8 +10896,10 @@ fn bindgen_test_layout___va_list_tag() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::core::ptr::null::<__va_list_tag>())).overflow_arg_area as *const _ as usize
+        {
+            let uninit = ::core::mem::MaybeUninit::<__va_list_tag>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).overflow_arg_area) as usize - ptr as usize }
         },
         8usize,
         concat!(
@@ -9657,7 +10910,11 @@ fn bindgen_test_layout___va_list_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<__va_list_tag>())).reg_save_area as *const _ as usize },
+        {
+            let uninit = ::core::mem::MaybeUninit::<__va_list_tag>::uninit();
+            let ptr = uninit.as_ptr();
+            unsafe { ::core::ptr::addr_of!((*ptr).reg_save_area) as usize - ptr as usize }
+        },
         16usize,
         concat!(
             "Offset of field: ",
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! I/O statistics per SQL statement, and the `vfsstat_statements` table.
+//!
+//! Statements are followed with `sqlite3_trace_v2`: `SQLITE_TRACE_STMT` marks
+//! a statement as running on the current thread until `SQLITE_TRACE_PROFILE`
+//! reports that it finished. I/O is attributed to the statement that started
+//! last among those running on the thread doing the I/O.
+
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{
+    cell::RefCell,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use std::{sync::Mutex, time::Duration};
+
+use crate::{
+    connections,
+    sqlite3ext::{sqlite3, sqlite3_stmt, SQLITE_OK, SQLITE_TRACE_PROFILE, SQLITE_TRACE_STMT},
+    table::{Row, Rows, Table},
+    vfs::Vfs,
+    StatField,
+};
+
+#[derive(Debug, Default)]
+pub struct StatementStats {
+    pub executions: AtomicU64,
+    pub reads: AtomicU64,
+    pub writes: AtomicU64,
+    pub bytes_in: AtomicU64,
+    pub bytes_out: AtomicU64,
+    pub syncs: AtomicU64,
+    /// Time spent in I/O methods, in nanoseconds
+    pub io_ns: AtomicU64,
+}
+
+impl StatementStats {
+    /// Adds `n` to the counter matching `field`, if any.
+    pub fn count(&self, field: StatField, n: u64) {
+        let counter = match field {
+            StatField::Read => &self.reads,
+            StatField::Write => &self.writes,
+            StatField::BytesIn => &self.bytes_in,
+            StatField::BytesOut => &self.bytes_out,
+            StatField::Sync => &self.syncs,
+            _ => return,
+        };
+        counter.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn io(&self, elapsed: Duration) {
+        let ns = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.io_ns.fetch_add(ns, Ordering::Relaxed);
+    }
+}
+
+/// [`StatementStats`] keyed by SQL text.
+#[derive(Debug, Default)]
+pub struct Statements {
+    entries: Mutex<BTreeMap<String, Arc<StatementStats>>>,
+}
+
+impl Statements {
+    /// Returns the statistics of `sql`, or `None` if it is new and there are
+    /// already `max` statements.
+    pub fn get(&self, sql: &str, max: usize) -> Option<Arc<StatementStats>> {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(stats) = entries.get(sql) {
+            return Some(stats.clone());
+        }
+        if entries.len() >= max {
+            return None;
+        }
+        let stats = Arc::new(StatementStats::default());
+        entries.insert(sql.to_string(), stats.clone());
+        Some(stats)
+    }
+
+    pub fn reset(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+
+    /// Returns a copy of all entries.
+    pub fn snapshot(&self) -> Vec<(String, Arc<StatementStats>)> {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries
+            .iter()
+            .map(|(sql, stats)| (sql.clone(), stats.clone()))
+            .collect()
+    }
+}
+
+std::thread_local! {
+    /// Statements running on this thread, by `sqlite3_stmt` address.
+    static RUNNING: RefCell<Vec<(usize, Arc<StatementStats>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Calls `f` with the statistics of the statement running on this thread, if
+/// any.
+pub fn with_current(f: impl FnOnce(&StatementStats)) {
+    let _ = RUNNING.try_with(|running| {
+        if let Some((_, stats)) = running.borrow().last() {
+            f(stats);
+        }
+    });
+}
+
+/// Follows the statements of `db`, if its main database is opened with a stat
+/// VFS whose `trace_statements` setting is on.
+///
+/// This replaces any `sqlite3_trace_v2` callback already set on `db`.
+///
+/// # Safety
+///
+/// `db` must be an open connection, and no other thread may use it during the
+/// call.
+pub unsafe fn register(db: *mut sqlite3) -> Result<(), String> {
+    match unsafe { Vfs::of_connection(db) } {
+        Some(vfs_) if vfs_.config.trace_statements.load(Ordering::Relaxed) != 0 => {}
+        _ => return Ok(()),
+    }
+    let ret = unsafe {
+        ((*crate::API).trace_v2.unwrap())(
+            db,
+            SQLITE_TRACE_STMT | SQLITE_TRACE_PROFILE,
+            Some(trace),
+            core::ptr::null_mut(),
+        )
+    };
+    if ret != SQLITE_OK as _ {
+        return Err(format!("Could not trace_v2, returned {}", ret));
+    }
+    Ok(())
+}
+
+unsafe extern "C" fn trace(
+    mask: ::core::ffi::c_uint,
+    _ctx: *mut ::core::ffi::c_void,
+    p: *mut ::core::ffi::c_void,
+    x: *mut ::core::ffi::c_void,
+) -> ::core::ffi::c_int {
+    let stmt = p as usize;
+    match mask {
+        SQLITE_TRACE_STMT => {
+            // Trigger programs report the statement again, with a comment as
+            // the text.
+            let x = unsafe { ::core::ffi::CStr::from_ptr(x as *const ::core::ffi::c_char) };
+            if x.to_bytes().starts_with(b"--") {
+                return 0;
+            }
+            let db = unsafe { ((*crate::API).db_handle.unwrap())(p as *mut sqlite3_stmt) };
+            let vfs_ = if let Some(vfs_) = unsafe { Vfs::of_connection(db) } {
+                vfs_
+            } else {
+                return 0;
+            };
+            connections::statement_started(stmt, vfs_.connections.get(db));
+            let sql = unsafe { ((*crate::API).sql.unwrap())(p as *mut sqlite3_stmt) };
+            let stats = if sql.is_null() {
+                None
+            } else {
+                let sql = unsafe { ::core::ffi::CStr::from_ptr(sql) }.to_string_lossy();
+                vfs_.statements
+                    .get(&sql, vfs_.config.max_statements.load(Ordering::Relaxed))
+            };
+            let _ = RUNNING.try_with(|running| {
+                let mut running = running.borrow_mut();
+                running.retain(|(s, _)| *s != stmt);
+                if let Some(stats) = stats {
+                    stats.executions.fetch_add(1, Ordering::Relaxed);
+                    running.push((stmt, stats));
+                }
+            });
+        }
+        SQLITE_TRACE_PROFILE => {
+            connections::statement_finished(stmt);
+            let _ = RUNNING.try_with(|running| {
+                running.borrow_mut().retain(|(s, _)| *s != stmt);
+            });
+        }
+        _ => {}
+    }
+    0
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_statements\0",
+    schema: b"CREATE TABLE x(sql,executions,reads,writes,bytes_in,bytes_out,syncs,io_ns)\0",
+    rows: Rows::Scan(rows),
+};
+
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    vfs_.statements
+        .snapshot()
+        .into_iter()
+        .map(|(sql, stats)| {
+            vec![
+                sql.into(),
+                stats.executions.load(Ordering::Relaxed).into(),
+                stats.reads.load(Ordering::Relaxed).into(),
+                stats.writes.load(Ordering::Relaxed).into(),
+                stats.bytes_in.load(Ordering::Relaxed).into(),
+                stats.bytes_out.load(Ordering::Relaxed).into(),
+                stats.syncs.load(Ordering::Relaxed).into(),
+                stats.io_ns.load(Ordering::Relaxed).into(),
+            ]
+        })
+        .collect()
+}
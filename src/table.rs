@@ -0,0 +1,376 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Read-only virtual tables whose rows are materialized from the [`Vfs`]
+//! statistics when a scan starts.
+//!
+//! Each table is described by a static [`Table`] that is passed as the client
+//! data of a single shared `sqlite3_module`.
+//!
+//! Every table has a trailing `vfs HIDDEN` column, which is also its last
+//! table-valued function argument, selecting the stat VFS whose statistics are
+//! shown.  It defaults to the stat VFS of the main database of the querying
+//! connection, or the one returned by [`Vfs::find`] if it is opened with
+//! another VFS.
+
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+
+use crate::{
+    sqlite3ext::{
+        sqlite3, sqlite3_context, sqlite3_index_info, sqlite3_int64, sqlite3_module, sqlite3_value,
+        sqlite3_vtab, sqlite3_vtab_cursor, SQLITE_CONSTRAINT, SQLITE_ERROR,
+        SQLITE_INDEX_CONSTRAINT_EQ, SQLITE_OK,
+    },
+    vfs::Vfs,
+};
+
+/// A column value of a [`Table`] row.
+#[derive(Clone, Debug)]
+pub enum Value {
+    Null,
+    Int(i64),
+    Real(f64),
+    Text(String),
+    Str(&'static str),
+}
+
+impl From<u64> for Value {
+    fn from(val: u64) -> Self {
+        Self::Int(val as i64)
+    }
+}
+
+impl From<&'static str> for Value {
+    fn from(val: &'static str) -> Self {
+        Self::Str(val)
+    }
+}
+
+impl From<String> for Value {
+    fn from(val: String) -> Self {
+        Self::Text(val)
+    }
+}
+
+pub type Row = Vec<Value>;
+
+/// Arguments of a [`Rows::Args`] table.
+pub type Args = [Option<String>];
+
+/// How the rows of a [`Table`] are produced.
+pub enum Rows {
+    /// Every row of the table.
+    Scan(fn(&Vfs) -> Vec<Row>),
+    /// Every row of the table, read with the help of the connection that is
+    /// querying it.
+    Connection(fn(&Vfs, *mut sqlite3) -> Result<Vec<Row>, String>),
+    /// Rows depending on table-valued function arguments, which are the
+    /// `HIDDEN` columns at indices `columns`. Each argument is passed in the
+    /// same order, as `None` when it is not given or `NULL`.
+    Args {
+        columns: &'static [::core::ffi::c_int],
+        rows: fn(&Vfs, &Args) -> Result<Vec<Row>, String>,
+    },
+}
+
+/// Definition of a read-only statistics table.
+pub struct Table {
+    /// Module name, NUL terminated.
+    pub name: &'static [u8],
+    /// `CREATE TABLE` statement declaring the columns, NUL terminated.
+    pub schema: &'static [u8],
+    /// Produces the rows of a scan.
+    pub rows: Rows,
+}
+
+#[repr(C)]
+struct TableVtab {
+    /// Base class.  Must be first
+    base: sqlite3_vtab,
+    table: &'static Table,
+    /// Connection the table belongs to
+    db: *mut sqlite3,
+    /// Index of the `vfs` column
+    vfs_column: ::core::ffi::c_int,
+}
+
+impl TableVtab {
+    /// Columns passed to [`TableFilter`] when constrained: the arguments of
+    /// [`Rows::Args`] tables, then `vfs`.
+    fn arg_columns(&self) -> Vec<::core::ffi::c_int> {
+        let mut columns = match self.table.rows {
+            Rows::Scan(_) | Rows::Connection(_) => Vec::new(),
+            Rows::Args { columns, .. } => columns.to_vec(),
+        };
+        columns.push(self.vfs_column);
+        columns
+    }
+}
+
+#[repr(C)]
+struct TableCursor {
+    /// Base class.  Must be first
+    base: sqlite3_vtab_cursor,
+    rows: Vec<Row>,
+    row: usize,
+    /// VFS the rows were produced from
+    vfs: Option<&'static Vfs>,
+}
+
+unsafe extern "C" fn TableConnect(
+    db: *mut sqlite3,
+    pAux: *mut ::core::ffi::c_void,
+    _argc: ::core::ffi::c_int,
+    _argv: *const *const ::core::ffi::c_char,
+    ppVTab: *mut *mut sqlite3_vtab,
+    _pzErr: *mut *mut ::core::ffi::c_char,
+) -> ::core::ffi::c_int {
+    let table: &'static Table = unsafe { &*(pAux as *const Table) };
+    // Replace the closing ")\0" of the schema.
+    let mut schema = table.schema[..table.schema.len() - 2].to_vec();
+    schema.extend_from_slice(b",vfs HIDDEN)\0");
+    let vfs_column = (table.schema.iter().filter(|b| **b == b',').count() + 1) as _;
+    let rc = unsafe { ((*crate::API).declare_vtab.unwrap())(db, schema.as_ptr() as _) };
+    if rc == SQLITE_OK as _ {
+        let pNew: Box<TableVtab> = Box::new(TableVtab {
+            base: sqlite3_vtab {
+                pModule: core::ptr::null_mut(),
+                nRef: 0,
+                zErrMsg: core::ptr::null_mut(),
+            },
+            table,
+            db,
+            vfs_column,
+        });
+        unsafe { *ppVTab = Box::into_raw(pNew) as _ };
+    }
+    rc
+}
+
+/// Equality constraints on the [argument columns](TableVtab::arg_columns) are
+/// passed to [`TableFilter`] in column order, with bit `i` of `idxNum` set if
+/// the `i`-th argument is given.
+unsafe extern "C" fn TableBestIndex(
+    pVTab: *mut sqlite3_vtab,
+    arg1: *mut sqlite3_index_info,
+) -> ::core::ffi::c_int {
+    let vtab: &TableVtab = unsafe { &*(pVTab as *const TableVtab) };
+    let columns = vtab.arg_columns();
+    let info = unsafe { &mut *arg1 };
+    let mut used: Vec<Option<usize>> = alloc::vec![None; columns.len()];
+    for i in 0..info.nConstraint as usize {
+        let constraint = unsafe { &*info.aConstraint.add(i) };
+        let arg = if let Some(arg) = columns.iter().position(|c| *c == constraint.iColumn) {
+            arg
+        } else {
+            continue;
+        };
+        if constraint.op != SQLITE_INDEX_CONSTRAINT_EQ as _ {
+            continue;
+        }
+        if constraint.usable == 0 {
+            // Try another plan where the argument is known.
+            return SQLITE_CONSTRAINT as _;
+        }
+        used[arg].get_or_insert(i);
+    }
+    let mut argv_index = 0;
+    for (arg, i) in used.iter().enumerate() {
+        if let Some(i) = i {
+            argv_index += 1;
+            let usage = unsafe { &mut *info.aConstraintUsage.add(*i) };
+            usage.argvIndex = argv_index;
+            usage.omit = 1;
+            info.idxNum |= 1 << arg;
+        }
+    }
+    SQLITE_OK as _
+}
+
+unsafe extern "C" fn TableDisconnect(pVTab: *mut sqlite3_vtab) -> ::core::ffi::c_int {
+    debug_assert!(!pVTab.is_null());
+    let _vtab: Box<TableVtab> = unsafe { Box::from_raw(pVTab as *mut TableVtab) };
+    SQLITE_OK as _
+}
+
+unsafe extern "C" fn TableOpen(
+    pVTab: *mut sqlite3_vtab,
+    ppCursor: *mut *mut sqlite3_vtab_cursor,
+) -> ::core::ffi::c_int {
+    let cursor: Box<TableCursor> = Box::new(TableCursor {
+        base: sqlite3_vtab_cursor { pVtab: pVTab },
+        rows: Vec::new(),
+        row: 0,
+        vfs: None,
+    });
+    unsafe { *ppCursor = Box::into_raw(cursor) as _ };
+    SQLITE_OK as _
+}
+
+unsafe extern "C" fn TableClose(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int {
+    debug_assert!(!arg1.is_null());
+    let _cur: Box<TableCursor> = unsafe { Box::from_raw(arg1 as *mut TableCursor) };
+    SQLITE_OK as _
+}
+
+/// Only a full table scan is supported, with the arguments chosen by
+/// [`TableBestIndex`].  xFilter takes a fresh snapshot of the rows.
+unsafe extern "C" fn TableFilter(
+    arg1: *mut sqlite3_vtab_cursor,
+    idxNum: ::core::ffi::c_int,
+    _idxStr: *const ::core::ffi::c_char,
+    argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+) -> ::core::ffi::c_int {
+    let mut ptr = core::ptr::NonNull::new(arg1 as *mut TableCursor).unwrap();
+    let cur: &mut TableCursor = unsafe { ptr.as_mut() };
+    let vtab = unsafe { &mut *(cur.base.pVtab as *mut TableVtab) };
+    let argv = if argc > 0 {
+        unsafe { core::slice::from_raw_parts(argv, argc as usize) }
+    } else {
+        &[]
+    };
+    let mut argv = argv.iter();
+    let mut args: Vec<Option<String>> = (0..vtab.arg_columns().len())
+        .map(|arg| {
+            let value = if idxNum & (1 << arg) != 0 {
+                *argv.next()?
+            } else {
+                return None;
+            };
+            let text = unsafe { ((*crate::API).value_text.unwrap())(value) };
+            if text.is_null() {
+                return None;
+            }
+            Some(
+                unsafe { core::ffi::CStr::from_ptr(text as _) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        })
+        .collect();
+    cur.rows = Vec::new();
+    cur.row = 0;
+    let vfs_ = match args.pop().flatten() {
+        Some(name) => Vfs::find_named(&name).ok_or_else(|| format!("no stat VFS named {:?}", name)),
+        None => unsafe { Vfs::of_connection(vtab.db) }
+            .or_else(Vfs::find)
+            .ok_or_else(|| "stat vfs is not registered".into()),
+    };
+    let rows = vfs_.and_then(|vfs_| {
+        cur.vfs = Some(vfs_);
+        match vtab.table.rows {
+            Rows::Scan(rows) => Ok(rows(vfs_)),
+            Rows::Connection(rows) => rows(vfs_, vtab.db),
+            Rows::Args { rows, .. } => rows(vfs_, &args),
+        }
+    });
+    match rows {
+        Ok(rows) => cur.rows = rows,
+        Err(err) => {
+            if let Some(ptr) = crate::err_to_sqlite3_str(err) {
+                unsafe { ((*crate::API).free.unwrap())(vtab.base.zErrMsg as _) };
+                vtab.base.zErrMsg = ptr;
+            }
+            return SQLITE_ERROR as _;
+        }
+    }
+    SQLITE_OK as _
+}
+
+unsafe extern "C" fn TableNext(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int {
+    let mut ptr = core::ptr::NonNull::new(arg1 as *mut TableCursor).unwrap();
+    let cur: &mut TableCursor = unsafe { ptr.as_mut() };
+    cur.row += 1;
+    SQLITE_OK as _
+}
+
+unsafe extern "C" fn TableEof(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int {
+    let ptr = core::ptr::NonNull::new(arg1 as *mut TableCursor).unwrap();
+    let cur: &TableCursor = unsafe { ptr.as_ref() };
+    (cur.row >= cur.rows.len()) as _
+}
+
+unsafe extern "C" fn TableColumn(
+    arg1: *mut sqlite3_vtab_cursor,
+    ctx: *mut sqlite3_context,
+    column: ::core::ffi::c_int,
+) -> ::core::ffi::c_int {
+    let ptr = core::ptr::NonNull::new(arg1 as *mut TableCursor).unwrap();
+    let cur: &TableCursor = unsafe { ptr.as_ref() };
+    let vtab: &TableVtab = unsafe { &*(cur.base.pVtab as *const TableVtab) };
+    if column == vtab.vfs_column {
+        if let Some(vfs_) = cur.vfs {
+            unsafe { crate::result_str(ctx, vfs_.name()) };
+        }
+        return SQLITE_OK as _;
+    }
+    match cur.rows[cur.row].get(column as usize) {
+        None | Some(Value::Null) => unsafe { ((*crate::API).result_null.unwrap())(ctx) },
+        Some(Value::Int(val)) => unsafe { ((*crate::API).result_int64.unwrap())(ctx, *val) },
+        Some(Value::Real(val)) => unsafe { ((*crate::API).result_double.unwrap())(ctx, *val) },
+        Some(Value::Text(val)) => unsafe { crate::result_str(ctx, val) },
+        Some(Value::Str(val)) => unsafe { crate::result_str(ctx, val) },
+    }
+    SQLITE_OK as _
+}
+
+unsafe extern "C" fn TableRowid(
+    arg1: *mut sqlite3_vtab_cursor,
+    pRowid: *mut sqlite3_int64,
+) -> ::core::ffi::c_int {
+    let ptr = core::ptr::NonNull::new(arg1 as *mut TableCursor).unwrap();
+    let cur: &TableCursor = unsafe { ptr.as_ref() };
+    unsafe {
+        *pRowid = cur.row as _;
+    }
+    SQLITE_OK as _
+}
+
+const TABLE_MODULE: sqlite3_module = sqlite3_module {
+    iVersion: 0,
+    xCreate: None,
+    xConnect: Some(TableConnect),
+    xBestIndex: Some(TableBestIndex),
+    xDisconnect: Some(TableDisconnect),
+    xDestroy: None,
+    xOpen: Some(TableOpen),
+    xClose: Some(TableClose),
+    xFilter: Some(TableFilter),
+    xNext: Some(TableNext),
+    xEof: Some(TableEof),
+    xColumn: Some(TableColumn),
+    xRowid: Some(TableRowid),
+    xUpdate: None,
+    xBegin: None,
+    xSync: None,
+    xCommit: None,
+    xRollback: None,
+    xFindFunction: None,
+    xRename: None,
+    xSavepoint: None,
+    xRelease: None,
+    xRollbackTo: None,
+    xShadowName: None,
+};
+
+impl Table {
+    /// Registers the module of the table on `db`.
+    ///
+    /// # Safety
+    ///
+    /// `db` must be an open connection.
+    pub unsafe fn create(&'static self, db: *mut sqlite3) -> Result<(), String> {
+        let ret = unsafe {
+            ((*crate::API).create_module.unwrap())(
+                db,
+                self.name.as_ptr() as _,
+                &TABLE_MODULE,
+                self as *const Self as *mut ::core::ffi::c_void,
+            )
+        };
+        if ret != SQLITE_OK as _ {
+            return Err(format!("Could not create_module, returned {}", ret));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Statistics grouped by the `vfsstat_tag` URI parameter, and the
+//! `vfsstat_tags` table.
+//!
+//! A main database opened with a `vfsstat_tag=<tag>` URI parameter, and its
+//! journal and WAL, also count their I/O in the [`FileStats`] of `<tag>`.
+
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec, vec::Vec};
+use std::sync::Mutex;
+
+use crate::{
+    table::{Row, Rows, Table},
+    vfs::Vfs,
+    FileStats, FileType, StatField,
+};
+
+/// [`FileStats`] of each tag.
+#[derive(Debug, Default)]
+pub struct Tags {
+    entries: Mutex<BTreeMap<String, Arc<FileStats>>>,
+}
+
+impl Tags {
+    /// Returns the statistics of `tag`, creating them if needed.
+    pub fn get(&self, tag: &str) -> Arc<FileStats> {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(stats) = entries.get(tag) {
+            return stats.clone();
+        }
+        let stats = Arc::new(FileStats::default());
+        entries.insert(tag.into(), stats.clone());
+        stats
+    }
+
+    pub fn reset(&self) {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        for stats in entries.values() {
+            stats.reset();
+        }
+    }
+
+    /// Returns the statistics of every tag, by tag.
+    pub fn snapshot(&self) -> Vec<(String, Arc<FileStats>)> {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries
+            .iter()
+            .map(|(tag, stats)| (tag.clone(), stats.clone()))
+            .collect()
+    }
+}
+
+pub static TABLE: Table = Table {
+    name: b"vfsstat_tags\0",
+    schema: b"CREATE TABLE x(tag,file,stat,count)\0",
+    rows: Rows::Scan(rows),
+};
+
+/// One row per tag and non-zero counter.
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    let mut rows = Vec::new();
+    for (tag, file_stats) in vfs_.tags.snapshot() {
+        for filetype in FileType::ALL {
+            let stats = file_stats.file(filetype);
+            for field in StatField::ALL {
+                let count = stats.get(field);
+                if count == 0 {
+                    continue;
+                }
+                rows.push(vec![
+                    tag.clone().into(),
+                    filetype.name().into(),
+                    field.name().into(),
+                    count.into(),
+                ]);
+            }
+        }
+    }
+    rows
+}
@@ -1,24 +1,84 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use alloc::{boxed::Box, format, string::String};
-use core::{convert::TryInto, pin::Pin};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    ffi::CString,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{
+    convert::TryInto,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{
+    sync::Mutex,
+    time::{Instant, SystemTime},
+};
 
 use log::debug;
 
 use crate::{
+    commits::{Commits, PendingCommit},
+    config::Config,
+    connections::{self, Connections},
+    errors::Errors,
+    iosize::IoSizes,
+    latency::Latencies,
+    marks::Marks,
+    pages::PageMap,
+    shm::{self, ShmLocks},
     sqlite3ext::{
-        sqlite3_file, sqlite3_int64, sqlite3_io_methods, sqlite3_vfs, SQLITE_FCNTL_VFSNAME,
-        SQLITE_OK, SQLITE_OPEN_MAIN_DB, SQLITE_OPEN_MAIN_JOURNAL, SQLITE_OPEN_MASTER_JOURNAL,
-        SQLITE_OPEN_SUBJOURNAL, SQLITE_OPEN_TEMP_DB, SQLITE_OPEN_TEMP_JOURNAL, SQLITE_OPEN_WAL,
+        sqlite3, sqlite3_file, sqlite3_int64, sqlite3_io_methods, sqlite3_vfs, SQLITE_BUSY,
+        SQLITE_FCNTL_BEGIN_ATOMIC_WRITE, SQLITE_FCNTL_COMMIT_PHASETWO, SQLITE_FCNTL_PDB,
+        SQLITE_FCNTL_PRAGMA, SQLITE_FCNTL_VFSNAME, SQLITE_FCNTL_VFS_POINTER, SQLITE_LOCK_EXCLUSIVE,
+        SQLITE_LOCK_NONE, SQLITE_LOCK_PENDING, SQLITE_LOCK_RESERVED, SQLITE_LOCK_SHARED,
+        SQLITE_NOTFOUND, SQLITE_OK, SQLITE_OPEN_MAIN_DB, SQLITE_OPEN_MAIN_JOURNAL,
+        SQLITE_OPEN_MASTER_JOURNAL, SQLITE_OPEN_SUBJOURNAL, SQLITE_OPEN_TEMP_DB,
+        SQLITE_OPEN_TEMP_JOURNAL, SQLITE_OPEN_WAL, SQLITE_SHM_EXCLUSIVE, SQLITE_SHM_LOCK,
+        SQLITE_SHM_UNLOCK,
     },
-    statcnt, FileStats, FileType,
+    statcnt,
+    statements::{self, Statements},
+    table::{Row, Rows, Table},
+    tags::Tags,
+    FileStats, FileType, IoOp, PathStats, StatField,
 };
 
 #[repr(C)]
 pub struct Vfs {
     inner: sqlite3_vfs,
     parent: core::ptr::NonNull<sqlite3_vfs>,
+    /// Registered name, pointed to by `inner.zName`
+    name: CString,
     pub file_stats: FileStats,
+    /// Statistics of every path opened through this VFS, kept after the files
+    /// are closed.
+    pub paths: Mutex<BTreeMap<String, Arc<PathStats>>>,
+    /// Time spent in the parent VFS by the I/O methods
+    pub latency: Latencies,
+    /// Request sizes of reads, writes and fetches
+    pub iosize: IoSizes,
+    /// Error codes returned by the parent VFS
+    pub errors: Errors,
+    /// WAL-index shared-memory locks
+    pub shm_locks: ShmLocks,
+    pub statements: Statements,
+    pub commits: Commits,
+    pub connections: Connections,
+    /// Statistics of each `vfsstat_tag`
+    pub tags: Tags,
+    /// Named copies of `file_stats`, kept across resets
+    pub marks: Marks,
+    pub config: Config,
+    /// Number of pages in the page heatmaps of all paths
+    pub tracked_pages: AtomicUsize,
+    /// When the VFS was created or last [`reset`](Self::reset)
+    pub last_reset: Mutex<(Instant, SystemTime)>,
 }
 
 impl Drop for Vfs {
@@ -30,9 +90,242 @@ pub struct StatConn {
     base: sqlite3_file,
     filetype: FileType,
     vfs: core::ptr::NonNull<Vfs>,
+    /// Statistics of the opened path, from [`Arc::into_raw`].  Null if the file
+    /// could not be opened.
+    path: *const PathStats,
+    /// Statistics of the main database of a main database, journal or WAL
+    /// file, from [`Arc::into_raw`].  Null for other files.
+    db: *const PathStats,
+    /// Statistics of the connection that owns the file, from
+    /// [`Arc::into_raw`].  For a main database file, null until
+    /// `SQLITE_FCNTL_PDB` is received; for other files, the current connection
+    /// when the file was opened, see [`connections`].
+    conn: *const FileStats,
+    /// Statistics of the `vfsstat_tag` URI parameter of a main database,
+    /// journal or WAL file, from [`Arc::into_raw`].  Null if there is none.
+    tag: *const FileStats,
+    /// Whether accounting is disabled with the `vfsstat=off` URI parameter
+    off: bool,
+    /// Page heatmap of a main or temp database opened without a name, from
+    /// [`Arc::into_raw`].  Null for other files, whose pages are tracked with
+    /// the statistics of their path.
+    pages: *const Mutex<PageMap>,
+    /// Current lock level, `SQLITE_LOCK_*`
+    lock_level: ::core::ffi::c_int,
+    /// When `lock_level` was entered
+    lock_since: Instant,
+    /// When each shm lock slot was acquired, if it is held
+    shm_since: [Option<Instant>; shm::SLOTS],
     real: sqlite3_file,
 }
 
+impl StatConn {
+    /// Adds `n` to `field`, both in the totals of this file's type and in the
+    /// statistics of its path.
+    fn count(&self, field: StatField, n: u64) {
+        if self.off {
+            return;
+        }
+        let vfs_ = unsafe { self.vfs.as_ref() };
+        vfs_.file_stats
+            .file(self.filetype)
+            .field(field)
+            .fetch_add(n, Ordering::Relaxed);
+        if let Some(path) = unsafe { self.path.as_ref() } {
+            path.stats.field(field).fetch_add(n, Ordering::Relaxed);
+        }
+        if let Some(conn) = unsafe { self.conn.as_ref() } {
+            conn.file(self.filetype)
+                .field(field)
+                .fetch_add(n, Ordering::Relaxed);
+            if self.filetype == FileType::Main {
+                unsafe { connections::main_used(self.conn) };
+            }
+        } else {
+            connections::with_current(|conn| {
+                conn.file(self.filetype)
+                    .field(field)
+                    .fetch_add(n, Ordering::Relaxed);
+            });
+        }
+        if let Some(tag) = unsafe { self.tag.as_ref() } {
+            tag.file(self.filetype)
+                .field(field)
+                .fetch_add(n, Ordering::Relaxed);
+        }
+        statements::with_current(|stats| stats.count(field, n));
+    }
+
+    /// Returns the write transaction in progress on the database of this file.
+    fn commit(&self) -> Option<&PendingCommit> {
+        unsafe { self.db.as_ref() }.map(|db| &db.commit)
+    }
+
+    /// Identifies this file as the owner of a write transaction.
+    fn owner(&self) -> usize {
+        self as *const Self as usize
+    }
+
+    /// Records the write transaction of this main file as a commit.
+    fn commit_done(&self) {
+        let db = if let Some(db) = unsafe { self.db.as_ref() } {
+            db
+        } else {
+            return;
+        };
+        if let Some(pending) = db.commit.take(self.owner()) {
+            let vfs_ = unsafe { self.vfs.as_ref() };
+            vfs_.commits.record(
+                &db.path,
+                pending,
+                vfs_.config.max_commits.load(Ordering::Relaxed),
+            );
+        }
+    }
+
+    /// Records the time since `start` as the latency of `op`.
+    fn record_latency(&self, op: IoOp, start: Instant) {
+        if self.off {
+            return;
+        }
+        let vfs_ = unsafe { self.vfs.as_ref() };
+        let elapsed = start.elapsed();
+        vfs_.latency.get(self.filetype, op).record(elapsed);
+        statements::with_current(|stats| stats.io(elapsed));
+    }
+
+    /// Tracks the lock level after xLock or xUnlock requested `level` and
+    /// returned `rc`.
+    fn lock_changed(&mut self, level: ::core::ffi::c_int, rc: ::core::ffi::c_int) {
+        let path = unsafe { self.path.as_ref() };
+        let from = self.lock_level;
+        let mut to = level;
+        if rc & 0xff == SQLITE_BUSY as ::core::ffi::c_int {
+            if let Some(path) = path {
+                path.locks.busy(from as usize, level as usize);
+            }
+            // A failed request for EXCLUSIVE leaves the file holding PENDING.
+            if level != SQLITE_LOCK_EXCLUSIVE as _
+                || from < SQLITE_LOCK_SHARED as _
+                || from >= SQLITE_LOCK_PENDING as _
+            {
+                return;
+            }
+            to = SQLITE_LOCK_PENDING as _;
+        } else if rc != SQLITE_OK as _ {
+            return;
+        }
+        if to == from {
+            return;
+        }
+        let now = Instant::now();
+        if let Some(path) = path {
+            path.locks
+                .transition(from as usize, to as usize, now - self.lock_since);
+        }
+        self.lock_level = to;
+        self.lock_since = now;
+    }
+
+    /// Tracks the shm lock slots after xShmLock was called with `offset`, `n`
+    /// and `flags` and returned `rc`.
+    fn shm_lock_changed(
+        &mut self,
+        offset: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
+        flags: ::core::ffi::c_int,
+        rc: ::core::ffi::c_int,
+    ) {
+        if self.off {
+            return;
+        }
+        let vfs_ = unsafe { self.vfs.as_ref() };
+        let mode = shm::mode_index(flags & SQLITE_SHM_EXCLUSIVE as ::core::ffi::c_int != 0);
+        let start = offset.max(0) as usize;
+        let end = (offset.saturating_add(n).max(0) as usize).min(shm::SLOTS);
+        let now = Instant::now();
+        for slot in start..end {
+            let stats = if let Some(stats) = vfs_.shm_locks.get(slot, mode) {
+                stats
+            } else {
+                continue;
+            };
+            if flags & SQLITE_SHM_LOCK as ::core::ffi::c_int != 0 {
+                if rc == SQLITE_OK as _ {
+                    stats.acquired.fetch_add(1, Ordering::Relaxed);
+                    self.shm_since[slot] = Some(now);
+                } else if rc & 0xff == SQLITE_BUSY as ::core::ffi::c_int {
+                    stats.busy.fetch_add(1, Ordering::Relaxed);
+                }
+            } else {
+                if rc == SQLITE_OK as _ {
+                    stats.released.fetch_add(1, Ordering::Relaxed);
+                }
+                if let Some(since) = self.shm_since[slot].take() {
+                    vfs_.shm_locks.released(slot, mode, now - since);
+                }
+            }
+        }
+    }
+
+    /// Records `rc` if it is not `SQLITE_OK`.
+    fn record_rc(&self, op: IoOp, rc: ::core::ffi::c_int) {
+        // xFileControl answers SQLITE_NOTFOUND for every opcode it does not
+        // handle, which is not an error.
+        if self.off
+            || rc == SQLITE_OK as _
+            || (op == IoOp::FileControl && rc == SQLITE_NOTFOUND as _)
+        {
+            return;
+        }
+        let vfs_ = unsafe { self.vfs.as_ref() };
+        vfs_.errors.record(self.filetype, op.name(), rc);
+    }
+
+    /// Records a successful read or write of `buf` at `iOfst` in the page
+    /// heatmap of database files.
+    fn record_page(
+        &self,
+        write: bool,
+        buf: *const ::core::ffi::c_void,
+        iAmt: ::core::ffi::c_int,
+        iOfst: sqlite3_int64,
+    ) {
+        if !matches!(self.filetype, FileType::Main | FileType::TempDb) || iAmt <= 0 || iOfst < 0 {
+            return;
+        }
+        let path = if let Some(path) = unsafe { self.path.as_ref() } {
+            path
+        } else {
+            return;
+        };
+        let vfs_ = unsafe { self.vfs.as_ref() };
+        let buf = unsafe { core::slice::from_raw_parts(buf as *const u8, iAmt as usize) };
+        unsafe { self.pages.as_ref() }
+            .unwrap_or(&path.pages)
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .record(
+                write,
+                buf,
+                iOfst as u64,
+                &vfs_.tracked_pages,
+                vfs_.config.max_pages.load(Ordering::Relaxed),
+            );
+    }
+
+    /// Records `iAmt` in the request size histogram of `op`.
+    fn record_size(&self, op: IoOp, iAmt: ::core::ffi::c_int) {
+        if self.off {
+            return;
+        }
+        let vfs_ = unsafe { self.vfs.as_ref() };
+        if let Some(histogram) = vfs_.iosize.get(self.filetype, op) {
+            histogram.record(iAmt.max(0) as u64);
+        }
+    }
+}
+
 #[no_mangle]
 pub static STAT_IO_METHODS: sqlite3_io_methods = sqlite3_io_methods {
     iVersion: 3,
@@ -56,18 +349,68 @@ pub static STAT_IO_METHODS: sqlite3_io_methods = sqlite3_io_methods {
     xUnfetch: Some(stat_unfetch),
 };
 
+/// Closes a file opened by [`stat_open`].
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`], which must not be used
+/// afterwards.
 #[no_mangle]
 pub unsafe extern "C" fn stat_close(arg1: *mut sqlite3_file) -> ::core::ffi::c_int {
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
+    let mut rc = SQLITE_OK as i32;
     if !stat_conn_ref.real.pMethods.is_null() {
-        return ((*stat_conn_ref.real.pMethods).xClose.unwrap())(&mut stat_conn_ref.real as *mut _);
+        stat_conn_ref.count(StatField::Close, 1);
+        let start = Instant::now();
+        rc = ((*stat_conn_ref.real.pMethods).xClose.unwrap())(&mut stat_conn_ref.real as *mut _);
+        stat_conn_ref.record_latency(IoOp::Close, start);
+        stat_conn_ref.record_rc(IoOp::Close, rc);
     }
-
-    SQLITE_OK as i32
+    if !stat_conn_ref.pages.is_null() {
+        // The heatmap of a file without a name is forgotten with the file.
+        let pages = Arc::from_raw(stat_conn_ref.pages);
+        if let Some(path) = stat_conn_ref.path.as_ref() {
+            path.unnamed_pages
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .retain(|other| !Arc::ptr_eq(other, &pages));
+        }
+        let tracked = pages.lock().unwrap_or_else(|err| err.into_inner()).clear();
+        stat_conn_ref
+            .vfs
+            .as_ref()
+            .tracked_pages
+            .fetch_sub(tracked, Ordering::Relaxed);
+        stat_conn_ref.pages = core::ptr::null();
+    }
+    if !stat_conn_ref.path.is_null() {
+        drop(Arc::from_raw(stat_conn_ref.path));
+        stat_conn_ref.path = core::ptr::null();
+    }
+    if !stat_conn_ref.db.is_null() {
+        (*stat_conn_ref.db).commit.end(stat_conn_ref.owner());
+        drop(Arc::from_raw(stat_conn_ref.db));
+        stat_conn_ref.db = core::ptr::null();
+    }
+    if !stat_conn_ref.conn.is_null() {
+        drop(Arc::from_raw(stat_conn_ref.conn));
+        stat_conn_ref.conn = core::ptr::null();
+    }
+    if !stat_conn_ref.tag.is_null() {
+        drop(Arc::from_raw(stat_conn_ref.tag));
+        stat_conn_ref.tag = core::ptr::null();
+    }
+    rc
 }
 
+/// Reads `iAmt` bytes at `iOfst` from a file opened by [`stat_open`].
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`] and `arg2` must be valid for
+/// writes of `iAmt` bytes.
 #[no_mangle]
 pub unsafe extern "C" fn stat_read(
     arg1: *mut sqlite3_file,
@@ -78,27 +421,30 @@ pub unsafe extern "C" fn stat_read(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    *statcnt!(
-        mut stat_conn_ref.vfs.as_mut().file_stats,
-        stat_conn_ref.filetype,
-        Read
-    ) += 1;
+    stat_conn_ref.count(StatField::Read, 1);
+    stat_conn_ref.record_size(IoOp::Read, iAmt);
+    let start = Instant::now();
     let ret = ((*stat_conn_ref.real.pMethods).xRead.unwrap())(
         &mut stat_conn_ref.real as *mut _,
         arg2,
         iAmt,
         iOfst,
     );
+    stat_conn_ref.record_latency(IoOp::Read, start);
+    stat_conn_ref.record_rc(IoOp::Read, ret);
     if ret == SQLITE_OK as i32 {
-        *statcnt!(
-            mut stat_conn_ref.vfs.as_mut().file_stats,
-            stat_conn_ref.filetype,
-            BytesIn
-        ) += iAmt as u64;
+        stat_conn_ref.count(StatField::BytesIn, iAmt as u64);
+        stat_conn_ref.record_page(false, arg2, iAmt, iOfst);
     }
     ret
 }
 
+/// Writes `iAmt` bytes at `iOfst` to a file opened by [`stat_open`].
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`] and `arg2` must be valid for
+/// reads of `iAmt` bytes.
 #[no_mangle]
 pub unsafe extern "C" fn stat_write(
     arg1: *mut sqlite3_file,
@@ -109,27 +455,33 @@ pub unsafe extern "C" fn stat_write(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    *statcnt!(
-        mut stat_conn_ref.vfs.as_mut().file_stats,
-        stat_conn_ref.filetype,
-        Write
-    ) += 1;
+    stat_conn_ref.count(StatField::Write, 1);
+    stat_conn_ref.record_size(IoOp::Write, iAmt);
+    let start = Instant::now();
     let ret = ((*stat_conn_ref.real.pMethods).xWrite.unwrap())(
         &mut stat_conn_ref.real as *mut _,
         arg2,
         iAmt,
         iOfst,
     );
+    stat_conn_ref.record_latency(IoOp::Write, start);
+    stat_conn_ref.record_rc(IoOp::Write, ret);
     if ret == SQLITE_OK as i32 {
-        *statcnt!(
-            mut stat_conn_ref.vfs.as_mut().file_stats,
-            stat_conn_ref.filetype,
-            BytesOut
-        ) += iAmt as u64;
+        stat_conn_ref.count(StatField::BytesOut, iAmt as u64);
+        stat_conn_ref.record_page(true, arg2, iAmt, iOfst);
+        let filetype = stat_conn_ref.filetype;
+        if let Some(commit) = stat_conn_ref.commit() {
+            commit.with(|commit| commit.write(filetype, iAmt as u64));
+        }
     }
     ret
 }
 
+/// Truncates a file opened by [`stat_open`] to `size` bytes.
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`].
 #[no_mangle]
 pub unsafe extern "C" fn stat_truncate(
     arg1: *mut sqlite3_file,
@@ -138,9 +490,22 @@ pub unsafe extern "C" fn stat_truncate(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xTruncate.unwrap())(&mut stat_conn_ref.real as *mut _, size)
+    stat_conn_ref.count(StatField::Truncate, 1);
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods).xTruncate.unwrap())(
+        &mut stat_conn_ref.real as *mut _,
+        size,
+    );
+    stat_conn_ref.record_latency(IoOp::Truncate, start);
+    stat_conn_ref.record_rc(IoOp::Truncate, ret);
+    ret
 }
 
+/// Syncs a file opened by [`stat_open`].
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`].
 #[no_mangle]
 pub unsafe extern "C" fn stat_sync(
     arg1: *mut sqlite3_file,
@@ -149,14 +514,27 @@ pub unsafe extern "C" fn stat_sync(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    *statcnt!(
-        mut stat_conn_ref.vfs.as_mut().file_stats,
-        stat_conn_ref.filetype,
-        Sync
-    ) += 1;
-    ((*stat_conn_ref.real.pMethods).xSync.unwrap())(&mut stat_conn_ref.real as *mut _, flags)
+    stat_conn_ref.count(StatField::Sync, 1);
+    let start = Instant::now();
+    let ret =
+        ((*stat_conn_ref.real.pMethods).xSync.unwrap())(&mut stat_conn_ref.real as *mut _, flags);
+    if ret == SQLITE_OK as _ {
+        let ns = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        if let Some(commit) = stat_conn_ref.commit() {
+            commit.with(|commit| commit.sync(ns));
+        }
+    }
+    stat_conn_ref.record_latency(IoOp::Sync, start);
+    stat_conn_ref.record_rc(IoOp::Sync, ret);
+    ret
 }
 
+/// Stores the size of a file opened by [`stat_open`] in `pSize`.
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`] and `pSize` must be valid for
+/// writes.
 #[no_mangle]
 pub unsafe extern "C" fn stat_file_size(
     arg1: *mut sqlite3_file,
@@ -165,9 +543,22 @@ pub unsafe extern "C" fn stat_file_size(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xFileSize.unwrap())(&mut stat_conn_ref.real as *mut _, pSize)
+    stat_conn_ref.count(StatField::FileSize, 1);
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods).xFileSize.unwrap())(
+        &mut stat_conn_ref.real as *mut _,
+        pSize,
+    );
+    stat_conn_ref.record_latency(IoOp::FileSize, start);
+    stat_conn_ref.record_rc(IoOp::FileSize, ret);
+    ret
 }
 
+/// Raises the lock of a file opened by [`stat_open`] to `arg2`.
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`].
 #[no_mangle]
 pub unsafe extern "C" fn stat_lock(
     arg1: *mut sqlite3_file,
@@ -176,14 +567,32 @@ pub unsafe extern "C" fn stat_lock(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    *statcnt!(
-        mut stat_conn_ref.vfs.as_mut().file_stats,
-        stat_conn_ref.filetype,
-        Lock
-    ) += 1;
-    ((*stat_conn_ref.real.pMethods).xLock.unwrap())(&mut stat_conn_ref.real as *mut _, arg2)
+    stat_conn_ref.count(StatField::Lock, 1);
+    let start = Instant::now();
+    let ret =
+        ((*stat_conn_ref.real.pMethods).xLock.unwrap())(&mut stat_conn_ref.real as *mut _, arg2);
+    stat_conn_ref.record_latency(IoOp::Lock, start);
+    stat_conn_ref.record_rc(IoOp::Lock, ret);
+    let from = stat_conn_ref.lock_level;
+    stat_conn_ref.lock_changed(arg2, ret);
+    if ret == SQLITE_OK as i32
+        && stat_conn_ref.filetype == FileType::Main
+        && from < SQLITE_LOCK_RESERVED as i32
+        && arg2 >= SQLITE_LOCK_RESERVED as i32
+    {
+        // A write transaction starts.
+        if let Some(commit) = stat_conn_ref.commit() {
+            commit.begin(stat_conn_ref.owner());
+        }
+    }
+    ret
 }
 
+/// Lowers the lock of a file opened by [`stat_open`] to `arg2`.
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`].
 #[no_mangle]
 pub unsafe extern "C" fn stat_unlock(
     arg1: *mut sqlite3_file,
@@ -192,14 +601,32 @@ pub unsafe extern "C" fn stat_unlock(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    *statcnt!(
-        mut stat_conn_ref.vfs.as_mut().file_stats,
-        stat_conn_ref.filetype,
-        Lock
-    ) += 1;
-    ((*stat_conn_ref.real.pMethods).xUnlock.unwrap())(&mut stat_conn_ref.real as *mut _, arg2)
+    stat_conn_ref.count(StatField::Unlock, 1);
+    let start = Instant::now();
+    let ret =
+        ((*stat_conn_ref.real.pMethods).xUnlock.unwrap())(&mut stat_conn_ref.real as *mut _, arg2);
+    stat_conn_ref.record_latency(IoOp::Unlock, start);
+    stat_conn_ref.record_rc(IoOp::Unlock, ret);
+    stat_conn_ref.lock_changed(arg2, ret);
+    if ret == SQLITE_OK as i32
+        && stat_conn_ref.filetype == FileType::Main
+        && arg2 < SQLITE_LOCK_RESERVED as i32
+    {
+        // The write transaction, if any, was rolled back.
+        if let Some(commit) = stat_conn_ref.commit() {
+            commit.end(stat_conn_ref.owner());
+        }
+    }
+    ret
 }
 
+/// Stores in `pResOut` whether a `RESERVED` lock is held on a file opened by
+/// [`stat_open`].
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`] and `pResOut` must be valid
+/// for writes.
 #[no_mangle]
 pub unsafe extern "C" fn stat_check_reserved_lock(
     arg1: *mut sqlite3_file,
@@ -208,17 +635,23 @@ pub unsafe extern "C" fn stat_check_reserved_lock(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    *statcnt!(
-        mut stat_conn_ref.vfs.as_mut().file_stats,
-        stat_conn_ref.filetype,
-        Lock
-    ) += 1;
-    ((*stat_conn_ref.real.pMethods).xCheckReservedLock.unwrap())(
+    stat_conn_ref.count(StatField::CheckReservedLock, 1);
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods).xCheckReservedLock.unwrap())(
         &mut stat_conn_ref.real as *mut _,
         pResOut,
-    )
+    );
+    stat_conn_ref.record_latency(IoOp::CheckReservedLock, start);
+    stat_conn_ref.record_rc(IoOp::CheckReservedLock, ret);
+    ret
 }
 
+/// Answers the file control `op` of a file opened by [`stat_open`].
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`] and `pArg` must be what SQLite
+/// documents for `op`.
 #[no_mangle]
 pub unsafe extern "C" fn stat_file_control(
     arg1: *mut sqlite3_file,
@@ -228,34 +661,91 @@ pub unsafe extern "C" fn stat_file_control(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    let rc = ((*stat_conn_ref.real.pMethods).xFileControl.unwrap())(
+    stat_conn_ref.count(StatField::FileControl, 1);
+    if op == SQLITE_FCNTL_VFS_POINTER as i32 && !pArg.is_null() {
+        // The file is accessed through this shim, not through its parent.
+        *(pArg as *mut *mut sqlite3_vfs) = stat_conn_ref.vfs.as_ref().as_sqlite3_vfs();
+        return SQLITE_OK as _;
+    }
+    if op == SQLITE_FCNTL_PRAGMA as i32 && stat_conn_ref.filetype == FileType::Main {
+        let rc = crate::pragmas::file_control(
+            stat_conn_ref.vfs.as_ref(),
+            pArg as *mut *mut ::core::ffi::c_char,
+        );
+        if rc != SQLITE_NOTFOUND as i32 {
+            return rc;
+        }
+    }
+    let start = Instant::now();
+    let mut rc = ((*stat_conn_ref.real.pMethods).xFileControl.unwrap())(
         &mut stat_conn_ref.real as *mut _,
         op,
         pArg,
     );
-    if rc == SQLITE_OK as i32 && op == SQLITE_FCNTL_VFSNAME as i32 {
-        // TODO:
-        //  *(char**)pArg = sqlite3_mprintf("vstat/%z", *(char**)pArg);
-        debug!("rc == SQLITE_OK as i32 && op == SQLITE_FCNTL_VFSNAME as i32");
+    stat_conn_ref.record_latency(IoOp::FileControl, start);
+    stat_conn_ref.record_rc(IoOp::FileControl, rc);
+    if op == SQLITE_FCNTL_PDB as i32 && !pArg.is_null() {
+        // pArg points to the connection that now owns this file.
+        let db = *(pArg as *mut *mut sqlite3);
+        let vfs_ = stat_conn_ref.vfs.as_ref();
+        let conn = Arc::into_raw(vfs_.connections.get(db));
+        if !stat_conn_ref.conn.is_null() {
+            drop(Arc::from_raw(stat_conn_ref.conn));
+        }
+        stat_conn_ref.conn = conn;
+    }
+    if stat_conn_ref.filetype == FileType::Main {
+        if rc == SQLITE_OK as i32 && op == SQLITE_FCNTL_BEGIN_ATOMIC_WRITE as i32 {
+            if let Some(commit) = stat_conn_ref.commit() {
+                commit.set_atomic(stat_conn_ref.owner());
+            }
+        } else if op == SQLITE_FCNTL_COMMIT_PHASETWO as i32 {
+            stat_conn_ref.commit_done();
+        }
+    }
+    if op == SQLITE_FCNTL_VFSNAME as i32 && !pArg.is_null() {
+        // Prefix the names of the VFSes below with the name of this shim, like
+        // other shims do, or answer with it if they do not know their names.
+        let name = stat_conn_ref.vfs.as_ref().name.as_ptr();
+        let zName = pArg as *mut *mut ::core::ffi::c_char;
+        let mprintf = (*crate::API).mprintf.unwrap();
+        if rc == SQLITE_OK as i32 && !(*zName).is_null() {
+            *zName = mprintf(b"%s/%z\0".as_ptr() as _, name, *zName);
+        } else if rc == SQLITE_NOTFOUND as i32 {
+            *zName = mprintf(b"%s\0".as_ptr() as _, name);
+            rc = SQLITE_OK as _;
+        }
     }
     rc
 }
 
+/// Returns the sector size of a file opened by [`stat_open`].
+///
+/// # Safety
+///
+/// `arg1` must be a file opened by [`stat_open`].
 #[no_mangle]
 pub unsafe extern "C" fn stat_sector_size(arg1: *mut sqlite3_file) -> ::core::ffi::c_int {
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xSectorSize.unwrap())(&mut stat_conn_ref.real as *mut _)
+    let start = Instant::now();
+    let ret =
+        ((*stat_conn_ref.real.pMethods).xSectorSize.unwrap())(&mut stat_conn_ref.real as *mut _);
+    stat_conn_ref.record_latency(IoOp::SectorSize, start);
+    ret
 }
 
 unsafe extern "C" fn stat_device_characteristics(arg1: *mut sqlite3_file) -> ::core::ffi::c_int {
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods)
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods)
         .xDeviceCharacteristics
-        .unwrap())(&mut stat_conn_ref.real as *mut _)
+        .unwrap())(&mut stat_conn_ref.real as *mut _);
+    stat_conn_ref.record_latency(IoOp::DeviceCharacteristics, start);
+    ret
 }
 
 unsafe extern "C" fn stat_shm_map(
@@ -268,13 +758,18 @@ unsafe extern "C" fn stat_shm_map(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xShmMap.unwrap())(
+    stat_conn_ref.count(StatField::ShmMap, 1);
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods).xShmMap.unwrap())(
         &mut stat_conn_ref.real as *mut _,
         iPg,
         pgsz,
         arg2,
         arg3,
-    )
+    );
+    stat_conn_ref.record_latency(IoOp::ShmMap, start);
+    stat_conn_ref.record_rc(IoOp::ShmMap, ret);
+    ret
 }
 
 unsafe extern "C" fn stat_shm_lock(
@@ -286,19 +781,46 @@ unsafe extern "C" fn stat_shm_lock(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xShmLock.unwrap())(
+    stat_conn_ref.count(StatField::ShmLock, 1);
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods).xShmLock.unwrap())(
         &mut stat_conn_ref.real as *mut _,
         offset,
         n,
         flags,
-    )
+    );
+    stat_conn_ref.record_latency(IoOp::ShmLock, start);
+    stat_conn_ref.record_rc(IoOp::ShmLock, ret);
+    stat_conn_ref.shm_lock_changed(offset, n, flags, ret);
+    if ret == SQLITE_OK as i32
+        && offset == 0
+        && flags == (SQLITE_SHM_LOCK | SQLITE_SHM_EXCLUSIVE) as i32
+    {
+        // The WAL WRITER lock was taken: a write transaction starts.
+        if let Some(commit) = stat_conn_ref.commit() {
+            commit.begin(stat_conn_ref.owner());
+        }
+    } else if ret == SQLITE_OK as i32
+        && offset == 0
+        && flags == (SQLITE_SHM_UNLOCK | SQLITE_SHM_EXCLUSIVE) as i32
+    {
+        // The WAL WRITER lock was released: another write transaction can
+        // start.
+        if let Some(commit) = stat_conn_ref.commit() {
+            commit.release(stat_conn_ref.owner());
+        }
+    }
+    ret
 }
 
 unsafe extern "C" fn stat_shm_barrier(arg1: *mut sqlite3_file) {
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xShmBarrier.unwrap())(&mut stat_conn_ref.real as *mut _)
+    stat_conn_ref.count(StatField::ShmBarrier, 1);
+    let start = Instant::now();
+    ((*stat_conn_ref.real.pMethods).xShmBarrier.unwrap())(&mut stat_conn_ref.real as *mut _);
+    stat_conn_ref.record_latency(IoOp::ShmBarrier, start);
 }
 unsafe extern "C" fn stat_shm_unmap(
     arg1: *mut sqlite3_file,
@@ -307,10 +829,15 @@ unsafe extern "C" fn stat_shm_unmap(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xShmUnmap.unwrap())(
+    stat_conn_ref.count(StatField::ShmUnmap, 1);
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods).xShmUnmap.unwrap())(
         &mut stat_conn_ref.real as *mut _,
         deleteFlag,
-    )
+    );
+    stat_conn_ref.record_latency(IoOp::ShmUnmap, start);
+    stat_conn_ref.record_rc(IoOp::ShmUnmap, ret);
+    ret
 }
 
 unsafe extern "C" fn stat_fetch(
@@ -322,12 +849,21 @@ unsafe extern "C" fn stat_fetch(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xFetch.unwrap())(
+    stat_conn_ref.count(StatField::Fetch, 1);
+    stat_conn_ref.record_size(IoOp::Fetch, iAmt);
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods).xFetch.unwrap())(
         &mut stat_conn_ref.real as *mut _,
         iOfst,
         iAmt,
         pp,
-    )
+    );
+    stat_conn_ref.record_latency(IoOp::Fetch, start);
+    stat_conn_ref.record_rc(IoOp::Fetch, ret);
+    if ret == SQLITE_OK as i32 && !(*pp).is_null() {
+        stat_conn_ref.count(StatField::BytesFetched, iAmt as u64);
+    }
+    ret
 }
 
 unsafe extern "C" fn stat_unfetch(
@@ -338,9 +874,25 @@ unsafe extern "C" fn stat_unfetch(
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(arg1 as *mut StatConn).expect("null file_ptr in stat_unfetch");
     let stat_conn_ref = stat_conn.as_mut();
-    ((*stat_conn_ref.real.pMethods).xUnfetch.unwrap())(&mut stat_conn_ref.real as *mut _, iOfst, p)
+    stat_conn_ref.count(StatField::Unfetch, 1);
+    let start = Instant::now();
+    let ret = ((*stat_conn_ref.real.pMethods).xUnfetch.unwrap())(
+        &mut stat_conn_ref.real as *mut _,
+        iOfst,
+        p,
+    );
+    stat_conn_ref.record_latency(IoOp::Unfetch, start);
+    stat_conn_ref.record_rc(IoOp::Unfetch, ret);
+    ret
 }
 
+/// Opens `zPath` with the parent VFS, counting the I/O of the file from now on.
+///
+/// # Safety
+///
+/// `vfs` must be a stat VFS, `file_ptr` must be valid for writes of its
+/// `szOsFile` bytes, and `zPath` must be null or a filename passed by SQLite,
+/// which may carry URI parameters.
 #[no_mangle]
 pub unsafe extern "C" fn stat_open(
     vfs: *mut sqlite3_vfs,
@@ -355,10 +907,9 @@ pub unsafe extern "C" fn stat_open(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
 
     let mut stat_conn: core::ptr::NonNull<StatConn> =
         core::ptr::NonNull::new(file_ptr as *mut StatConn).expect("null file_ptr in stat_open");
@@ -387,17 +938,108 @@ pub unsafe extern "C" fn stat_open(
     } else {
         stat_conn_ref.filetype = FileType::Transient;
     }
-    *statcnt!(mut vfs_.file_stats, stat_conn_ref.filetype, Open) += 1;
+    // URI parameters are only attached to the names of main databases, and
+    // of their journals and WALs.
+    let has_uri = !zPath.is_null()
+        && matches!(
+            stat_conn_ref.filetype,
+            FileType::Main | FileType::Journal | FileType::Wal
+        );
+    stat_conn_ref.off =
+        has_uri && ((*crate::API).uri_boolean.unwrap())(zPath, b"vfsstat\0".as_ptr() as _, 1) == 0;
+    let tag = if has_uri {
+        let tag = ((*crate::API).uri_parameter.unwrap())(zPath, b"vfsstat_tag\0".as_ptr() as _);
+        (!tag.is_null()).then(|| {
+            core::ffi::CStr::from_ptr(tag)
+                .to_string_lossy()
+                .into_owned()
+        })
+    } else {
+        None
+    };
+    stat_conn_ref.path = core::ptr::null();
+    stat_conn_ref.db = core::ptr::null();
+    stat_conn_ref.conn = core::ptr::null();
+    stat_conn_ref.tag = core::ptr::null();
+    stat_conn_ref.pages = core::ptr::null();
+    if !stat_conn_ref.off {
+        statcnt!(vfs_.file_stats, stat_conn_ref.filetype, Open += 1);
+        connections::with_current(|conn| {
+            statcnt!(conn, stat_conn_ref.filetype, Open += 1);
+        });
+        if let Some(tag) = &tag {
+            statcnt!(vfs_.tags.get(tag), stat_conn_ref.filetype, Open += 1);
+        }
+        if parent_open != SQLITE_OK as _ {
+            vfs_.errors
+                .record(stat_conn_ref.filetype, StatField::Open.name(), parent_open);
+        }
+    }
 
+    stat_conn_ref.lock_level = SQLITE_LOCK_NONE as _;
+    stat_conn_ref.lock_since = Instant::now();
+    stat_conn_ref.shm_since = [None; shm::SLOTS];
     if parent_open == SQLITE_OK as _ {
         stat_conn_ref.base.pMethods = &STAT_IO_METHODS;
     } else {
         stat_conn_ref.base.pMethods = core::ptr::null_mut();
     }
+    if parent_open == SQLITE_OK as _ && !stat_conn_ref.off {
+        // Files opened without a name are grouped by type.
+        let path = if zPath.is_null() {
+            format!("<{}>", stat_conn_ref.filetype.name())
+        } else {
+            core::ffi::CStr::from_ptr(zPath)
+                .to_string_lossy()
+                .into_owned()
+        };
+        let path_stats = vfs_.path_stats(&path, stat_conn_ref.filetype);
+        if let Some(tag) = tag {
+            // Released in xClose, which is only called if the file was opened.
+            stat_conn_ref.tag = Arc::into_raw(vfs_.tags.get(&tag));
+            *path_stats.tag.lock().unwrap_or_else(|err| err.into_inner()) = Some(tag);
+        }
+        path_stats
+            .stats
+            .field(StatField::Open)
+            .fetch_add(1, Ordering::Relaxed);
+        stat_conn_ref.db = match stat_conn_ref.filetype {
+            FileType::Main => Arc::into_raw(path_stats.clone()),
+            FileType::Journal | FileType::Wal => path
+                .strip_suffix("-journal")
+                .or_else(|| path.strip_suffix("-wal"))
+                .and_then(|db| {
+                    let paths = vfs_.paths.lock().unwrap_or_else(|err| err.into_inner());
+                    paths.get(db).cloned()
+                })
+                .map_or(core::ptr::null(), Arc::into_raw),
+            _ => core::ptr::null(),
+        };
+        if stat_conn_ref.filetype != FileType::Main {
+            // Released in xClose, like the connection of a main database.
+            stat_conn_ref.conn = connections::current().map_or(core::ptr::null(), Arc::into_raw);
+        }
+        if zPath.is_null() && matches!(stat_conn_ref.filetype, FileType::Main | FileType::TempDb) {
+            // Released in xClose.
+            let pages = Arc::new(Mutex::default());
+            path_stats
+                .unnamed_pages
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .push(pages.clone());
+            stat_conn_ref.pages = Arc::into_raw(pages);
+        }
+        stat_conn_ref.path = Arc::into_raw(path_stats);
+    }
     stat_conn_ref.vfs = vfs_ptr;
     parent_open
 }
 
+/// Deletes `zName` with the parent VFS.
+///
+/// # Safety
+///
+/// `vfs` must be a stat VFS and `zName` a NUL-terminated string.
 #[no_mangle]
 pub unsafe extern "C" fn stat_delete(
     vfs: *mut sqlite3_vfs,
@@ -410,15 +1052,28 @@ pub unsafe extern "C" fn stat_delete(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
 
-    *statcnt!(mut vfs_.file_stats, FileType::Any, Delete) += 1;
-    (vfs_.parent.as_ref().xDelete.unwrap())(vfs_.parent.as_ptr() as _, zName, syncDir)
+    statcnt!(vfs_.file_stats, FileType::Any, Delete += 1);
+    connections::with_current(|conn| {
+        statcnt!(conn, FileType::Any, Delete += 1);
+    });
+    let rc = (vfs_.parent.as_ref().xDelete.unwrap())(vfs_.parent.as_ptr() as _, zName, syncDir);
+    if rc != SQLITE_OK as _ {
+        vfs_.errors
+            .record(FileType::Any, StatField::Delete.name(), rc);
+    }
+    rc
 }
 
+/// Checks the access `flags` to `zName` with the parent VFS.
+///
+/// # Safety
+///
+/// `vfs` must be a stat VFS, `zName` a NUL-terminated string and `pResOut` must
+/// be valid for writes.
 #[no_mangle]
 pub unsafe extern "C" fn stat_access(
     vfs: *mut sqlite3_vfs,
@@ -432,12 +1087,20 @@ pub unsafe extern "C" fn stat_access(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
-    *statcnt!(mut vfs_.file_stats, FileType::Any, Access) += 1;
-    (vfs_.parent.as_ref().xAccess.unwrap())(vfs_.parent.as_ptr() as _, zName, flags, pResOut)
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
+    statcnt!(vfs_.file_stats, FileType::Any, Access += 1);
+    connections::with_current(|conn| {
+        statcnt!(conn, FileType::Any, Access += 1);
+    });
+    let rc =
+        (vfs_.parent.as_ref().xAccess.unwrap())(vfs_.parent.as_ptr() as _, zName, flags, pResOut);
+    if rc != SQLITE_OK as _ {
+        vfs_.errors
+            .record(FileType::Any, StatField::Access.name(), rc);
+    }
+    rc
 }
 
 unsafe extern "C" fn stat_full_pathname(
@@ -452,12 +1115,20 @@ unsafe extern "C" fn stat_full_pathname(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
-    *statcnt!(mut vfs_.file_stats, FileType::Any, FullPath) += 1;
-    (vfs_.parent.as_ref().xFullPathname.unwrap())(vfs_.parent.as_ptr() as _, zName, nOut, zOut)
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
+    statcnt!(vfs_.file_stats, FileType::Any, FullPath += 1);
+    connections::with_current(|conn| {
+        statcnt!(conn, FileType::Any, FullPath += 1);
+    });
+    let rc =
+        (vfs_.parent.as_ref().xFullPathname.unwrap())(vfs_.parent.as_ptr() as _, zName, nOut, zOut);
+    if rc != SQLITE_OK as _ {
+        vfs_.errors
+            .record(FileType::Any, StatField::FullPath.name(), rc);
+    }
+    rc
 }
 
 unsafe extern "C" fn stat_dlopen(
@@ -470,10 +1141,9 @@ unsafe extern "C" fn stat_dlopen(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
     (vfs_.parent.as_ref().xDlOpen.unwrap())(vfs_.parent.as_ptr() as _, zFilename)
 }
 
@@ -488,10 +1158,9 @@ unsafe extern "C" fn stat_dlerror(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
     (vfs_.parent.as_ref().xDlError.unwrap())(vfs_.parent.as_ptr() as _, nByte, zErrMsg)
 }
 
@@ -512,10 +1181,9 @@ unsafe extern "C" fn stat_dlsym(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
     (vfs_.parent.as_ref().xDlSym.unwrap())(vfs_.parent.as_ptr() as _, arg2, zSymbol)
 }
 
@@ -526,10 +1194,9 @@ unsafe extern "C" fn stat_dlclose(arg1: *mut sqlite3_vfs, arg2: *mut ::core::ffi
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
     (vfs_.parent.as_ref().xDlClose.unwrap())(vfs_.parent.as_ptr() as _, arg2)
 }
 
@@ -544,11 +1211,13 @@ unsafe extern "C" fn stat_randomness(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
-    *statcnt!(mut vfs_.file_stats, FileType::Any, Random) += 1;
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
+    statcnt!(vfs_.file_stats, FileType::Any, Random += 1);
+    connections::with_current(|conn| {
+        statcnt!(conn, FileType::Any, Random += 1);
+    });
     (vfs_.parent.as_ref().xRandomness.unwrap())(vfs_.parent.as_ptr() as _, nByte, zOut)
 }
 
@@ -562,11 +1231,13 @@ unsafe extern "C" fn stat_sleep(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
-    *statcnt!(mut vfs_.file_stats, FileType::Any, Sleep) += 1;
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
+    statcnt!(vfs_.file_stats, FileType::Any, Sleep += 1);
+    connections::with_current(|conn| {
+        statcnt!(conn, FileType::Any, Sleep += 1);
+    });
     (vfs_.parent.as_ref().xSleep.unwrap())(vfs_.parent.as_ptr() as _, microseconds)
 }
 
@@ -580,11 +1251,13 @@ unsafe extern "C" fn stat_current_time(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
-    *statcnt!(mut vfs_.file_stats, FileType::Any, CurrentTime) += 1;
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
+    statcnt!(vfs_.file_stats, FileType::Any, CurrentTime += 1);
+    connections::with_current(|conn| {
+        statcnt!(conn, FileType::Any, CurrentTime += 1);
+    });
     (vfs_.parent.as_ref().xCurrentTime.unwrap())(vfs_.parent.as_ptr() as _, arg2)
 }
 
@@ -599,10 +1272,9 @@ unsafe extern "C" fn stat_get_last_error(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
     (vfs_.parent.as_ref().xGetLastError.unwrap())(vfs_.parent.as_ptr() as _, arg2, arg3)
 }
 
@@ -616,33 +1288,234 @@ unsafe extern "C" fn stat_current_time_int64(
         panic!("Could not find default sqlite3 vfs");
     };
     let sqlite_vfs: &mut sqlite3_vfs = vfs_ptr.as_mut();
-    let mut vfs_ptr: core::ptr::NonNull<Vfs> =
-        core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
-            .expect("pAppData of stat vfs is null");
-    let vfs_ = vfs_ptr.as_mut();
-    *statcnt!(mut vfs_.file_stats, FileType::Any, CurrentTime) += 1;
+    let vfs_ptr: core::ptr::NonNull<Vfs> = core::ptr::NonNull::new(sqlite_vfs.pAppData as *mut Vfs)
+        .expect("pAppData of stat vfs is null");
+    let vfs_ = vfs_ptr.as_ref();
+    statcnt!(vfs_.file_stats, FileType::Any, CurrentTime += 1);
+    connections::with_current(|conn| {
+        statcnt!(conn, FileType::Any, CurrentTime += 1);
+    });
     (vfs_.parent.as_ref().xCurrentTimeInt64.unwrap())(vfs_.parent.as_ptr() as _, arg2)
 }
 
-pub const VFS_NAME: &[u8] = b"vfsstat_rs\0";
+/// Name of the stat VFS registered when the extension is loaded.
+pub const VFS_NAME: &str = "vfsstat_rs";
+
+/// The `vfsstat_vfs` table: one row per registered stat VFS.
+pub static INSTANCES_TABLE: Table = Table {
+    name: b"vfsstat_vfs\0",
+    schema: b"CREATE TABLE x(name,parent)\0",
+    rows: Rows::Scan(instance_rows),
+};
+
+fn instance_rows(_vfs: &Vfs) -> Vec<Row> {
+    Vfs::instances()
+        .into_iter()
+        .map(|vfs_| {
+            vec![
+                vfs_.name().to_string().into(),
+                vfs_.parent_name().to_string().into(),
+            ]
+        })
+        .collect()
+}
+
+/// Addresses of the registered stat VFSes, in registration order.  They are
+/// never unregistered or freed.
+static INSTANCES: Mutex<Vec<usize>> = Mutex::new(Vec::new());
 
 impl Vfs {
-    pub fn new() -> Result<Pin<Box<Self>>, String> {
-        let default_ptr = unsafe { ((*crate::API).vfs_find.unwrap())(core::ptr::null()) };
-        let default = if let Some(default) = core::ptr::NonNull::new(default_ptr) {
-            default
+    /// Returns the first registered stat VFS, which is the one named
+    /// [`VFS_NAME`] unless it was not registered.
+    pub fn find() -> Option<&'static Self> {
+        Self::instances().into_iter().next()
+    }
+
+    /// Looks up the registered stat VFS named `name`.
+    pub fn find_named(name: &str) -> Option<&'static Self> {
+        Self::instances()
+            .into_iter()
+            .find(|vfs_| vfs_.name() == name)
+    }
+
+    /// Returns every registered stat VFS, in registration order.
+    pub fn instances() -> Vec<&'static Self> {
+        INSTANCES
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .iter()
+            .map(|addr| unsafe { &*(*addr as *const Self) })
+            .collect()
+    }
+
+    /// Returns the `sqlite3_vfs` registered for this VFS.
+    pub fn as_sqlite3_vfs(&self) -> *mut sqlite3_vfs {
+        &self.inner as *const sqlite3_vfs as *mut sqlite3_vfs
+    }
+
+    /// Returns the stat VFS that `vfs` points to, if it is one.
+    pub fn from_sqlite3_vfs(vfs: *const sqlite3_vfs) -> Option<&'static Self> {
+        Self::instances()
+            .into_iter()
+            .find(|vfs_| core::ptr::eq(vfs_.as_sqlite3_vfs(), vfs))
+    }
+
+    /// Returns the stat VFS of the main database of `db`, if it is opened
+    /// with one.
+    ///
+    /// # Safety
+    ///
+    /// `db` must be an open connection.
+    pub unsafe fn of_connection(db: *mut sqlite3) -> Option<&'static Self> {
+        let mut vfs: *mut sqlite3_vfs = core::ptr::null_mut();
+        let rc = unsafe {
+            ((*crate::API).file_control.unwrap())(
+                db,
+                b"main\0".as_ptr() as _,
+                SQLITE_FCNTL_VFS_POINTER as _,
+                &mut vfs as *mut *mut sqlite3_vfs as _,
+            )
+        };
+        if rc != SQLITE_OK as _ {
+            return None;
+        }
+        Self::from_sqlite3_vfs(vfs)
+    }
+
+    /// Zeroes every statistic, keeping the paths and connections that are
+    /// known, and restarts the [`last_reset`](Self::last_reset) clock.
+    pub fn reset(&self) {
+        self.file_stats.reset();
+        {
+            let paths = self.paths.lock().unwrap_or_else(|err| err.into_inner());
+            for path_stats in paths.values() {
+                path_stats.stats.reset();
+                path_stats.locks.reset();
+                let unnamed_pages = path_stats
+                    .unnamed_pages
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner());
+                for pages in
+                    core::iter::once(&path_stats.pages).chain(unnamed_pages.iter().map(Arc::as_ref))
+                {
+                    let pages = pages.lock().unwrap_or_else(|err| err.into_inner()).clear();
+                    self.tracked_pages.fetch_sub(pages, Ordering::Relaxed);
+                }
+            }
+        }
+        self.latency.reset();
+        self.iosize.reset();
+        self.errors.reset();
+        self.shm_locks.reset();
+        self.statements.reset();
+        self.commits.reset();
+        self.connections.reset();
+        self.tags.reset();
+        *self
+            .last_reset
+            .lock()
+            .unwrap_or_else(|err| err.into_inner()) = (Instant::now(), SystemTime::now());
+    }
+
+    /// Name under which this VFS is registered.
+    pub fn name(&self) -> &str {
+        self.name.to_str().unwrap_or_default()
+    }
+
+    /// Name of the VFS the calls are forwarded to.
+    pub fn parent_name(&self) -> &str {
+        unsafe { core::ffi::CStr::from_ptr(self.parent.as_ref().zName) }
+            .to_str()
+            .unwrap_or_default()
+    }
+
+    /// Returns the statistics of `path`, creating them on first use.
+    pub fn path_stats(&self, path: &str, filetype: FileType) -> Arc<PathStats> {
+        let mut paths = self.paths.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(path_stats) = paths.get(path) {
+            return path_stats.clone();
+        }
+        let path_stats = Arc::new(PathStats::new(path.to_string(), filetype));
+        paths.insert(path.to_string(), path_stats.clone());
+        path_stats
+    }
+
+    /// Registers a stat VFS named `name` that forwards to the VFS named
+    /// `parent`, or to the default VFS if `parent` is `None`.  If
+    /// `make_default` is set, it becomes the default VFS.
+    ///
+    /// If a stat VFS named `name` is already registered, it is returned
+    /// instead, as long as it forwards to `parent` or `parent` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// The extension must be loaded, as this registers the VFS with SQLite.
+    pub unsafe fn register(
+        name: &str,
+        parent: Option<&str>,
+        make_default: bool,
+    ) -> Result<&'static Self, String> {
+        let name = CString::new(name).map_err(|_| "VFS name contains a NUL byte".to_string())?;
+        let parent_name = parent
+            .map(CString::new)
+            .transpose()
+            .map_err(|_| "parent VFS name contains a NUL byte".to_string())?;
+        let existing = unsafe { ((*crate::API).vfs_find.unwrap())(name.as_ptr()) };
+        if !existing.is_null() {
+            let vfs_ = match Self::from_sqlite3_vfs(existing) {
+                Some(vfs_) if parent.map_or(true, |parent| parent == vfs_.parent_name()) => vfs_,
+                _ => return Err(format!("a VFS named {:?} is already registered", name)),
+            };
+            if make_default {
+                let ret = unsafe { ((*crate::API).vfs_register.unwrap())(existing, 1) };
+                if ret != SQLITE_OK as _ {
+                    return Err(format!("Vfs::new() sqlite3_vfs_register returned {}", ret,));
+                }
+            }
+            return Ok(vfs_);
+        }
+        let parent_ptr = unsafe {
+            ((*crate::API).vfs_find.unwrap())(
+                parent_name
+                    .as_ref()
+                    .map_or(core::ptr::null(), |parent| parent.as_ptr()),
+            )
+        };
+        let parent = if let Some(parent) = core::ptr::NonNull::new(parent_ptr) {
+            parent
+        } else if let Some(parent_name) = parent_name {
+            return Err(format!("Could not find sqlite3 vfs {:?}", parent_name));
         } else {
             return Err("Could not find default sqlite3 vfs".into());
         };
+        let mut self_ = Self::new(name, parent)?;
+        let ret =
+            unsafe { ((*crate::API).vfs_register.unwrap())(&mut self_.inner, make_default.into()) };
+        if ret != SQLITE_OK as _ {
+            return Err(format!("Vfs::new() sqlite3_vfs_register returned {}", ret,));
+        }
+        let self_: &'static Self = Box::leak(Pin::into_inner(self_));
+        INSTANCES
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push(self_ as *const Self as usize);
+        Ok(self_)
+    }
 
-        let default_ref = unsafe { default.as_ref() };
-        let mut inner = *default_ref;
+    fn new(
+        name: CString,
+        parent: core::ptr::NonNull<sqlite3_vfs>,
+    ) -> Result<Pin<Box<Self>>, String> {
+        let parent_ref = unsafe { parent.as_ref() };
+        let mut inner = *parent_ref;
         debug!(
-            "default vfs name: {:?} ",
+            "parent vfs name: {:?} ",
             unsafe { core::ffi::CStr::from_ptr(inner.zName) }.to_str()
         );
-        inner.iVersion = 2;
-        inner.zName = VFS_NAME.as_ptr() as _;
+        // Version 3 methods are not wrapped, and version 2 ones only if the
+        // parent has them.
+        inner.iVersion = inner.iVersion.min(2);
+        inner.zName = name.as_ptr();
         inner.pNext = core::ptr::null_mut();
         inner.pAppData = core::ptr::null_mut();
         inner.xOpen = Some(stat_open);
@@ -664,16 +1537,25 @@ impl Vfs {
         inner.szOsFile += fsize;
 
         let mut self_ = Box::pin(Vfs {
-            parent: default,
+            parent,
             inner,
+            name,
             file_stats: FileStats::default(),
+            paths: Mutex::new(BTreeMap::new()),
+            latency: Latencies::default(),
+            iosize: IoSizes::default(),
+            errors: Errors::default(),
+            shm_locks: ShmLocks::default(),
+            statements: Statements::default(),
+            commits: Commits::default(),
+            connections: Connections::default(),
+            tags: Tags::default(),
+            marks: Marks::default(),
+            config: Config::from_env(),
+            tracked_pages: AtomicUsize::new(0),
+            last_reset: Mutex::new((Instant::now(), SystemTime::now())),
         });
-        self_.inner.pAppData =
-            unsafe { core::mem::transmute((self_.as_ref().get_ref()) as *const _) };
-        let ret = unsafe { ((*crate::API).vfs_register.unwrap())(&mut self_.inner, 1) };
-        if ret != SQLITE_OK as _ {
-            return Err(format!("Vfs::new() sqlite3_vfs_register returned {}", ret,));
-        }
+        self_.inner.pAppData = self_.as_ref().get_ref() as *const Vfs as *mut ::core::ffi::c_void;
         Ok(self_)
     }
 }
@@ -1,21 +1,93 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use alloc::{boxed::Box, format, string::String};
+use alloc::{boxed::Box, format, string::String, sync::Arc};
+use core::{convert::TryInto, sync::atomic::Ordering};
 
 use crate::{
     sqlite3ext::{
         sqlite3, sqlite3_context, sqlite3_index_info, sqlite3_int64, sqlite3_module, sqlite3_value,
-        sqlite3_vfs, sqlite3_vtab, sqlite3_vtab_cursor, SQLITE_ERROR, SQLITE_OK,
+        sqlite3_vtab, sqlite3_vtab_cursor, SQLITE_CONSTRAINT, SQLITE_ERROR,
+        SQLITE_INDEX_CONSTRAINT_EQ, SQLITE_INDEX_SCAN_UNIQUE, SQLITE_INTEGER, SQLITE_NULL,
+        SQLITE_OK,
     },
-    statcnt, FileType, StatField,
+    vfs::Vfs,
+    FileStats, FileType, StatField,
 };
 
+/// Column indices.  The hidden columns are the table-valued function
+/// arguments: `vtabstat(file, stat, scope, vfs)`.
+const FILE_COLUMN: ::core::ffi::c_int = 0;
+const STAT_COLUMN: ::core::ffi::c_int = 1;
+const COUNT_COLUMN: ::core::ffi::c_int = 2;
+const FILE_ARG_COLUMN: ::core::ffi::c_int = 3;
+const STAT_ARG_COLUMN: ::core::ffi::c_int = 4;
+const SCOPE_COLUMN: ::core::ffi::c_int = 5;
+const VFS_COLUMN: ::core::ffi::c_int = 6;
+
+/// `idxNum` bits of the constraints passed to [`VtabFilter`].
+const FILE_CONSTRAINT: ::core::ffi::c_int = 1;
+const STAT_CONSTRAINT: ::core::ffi::c_int = 2;
+const SCOPE_CONSTRAINT: ::core::ffi::c_int = 4;
+const VFS_CONSTRAINT: ::core::ffi::c_int = 8;
+
+#[repr(C)]
+struct VfsStatVtab {
+    /// Base class.  Must be first
+    base: sqlite3_vtab,
+    /// Connection the table belongs to
+    db: *mut sqlite3,
+}
+
 #[repr(C)]
 struct VfsStatCursor {
     /// Base class.  Must be first
     base: sqlite3_vtab_cursor,
+    /// VFS whose counters are returned
+    vfs: Option<&'static Vfs>,
     filetype: FileType,
     field: StatField,
+    eof: bool,
+    /// Only counter file type or field to return, if constrained
+    only_file: Option<FileType>,
+    only_field: Option<StatField>,
+    /// Statistics of the querying connection for the `'connection'` scope,
+    /// `None` for the `'process'` scope
+    connection: Option<Arc<FileStats>>,
+}
+
+/// Number of counters of a VFS.
+const COUNTERS: usize = FileType::ALL.len() * StatField::ALL.len();
+
+/// A counter of a VFS, in the `'process'` scope or, if `connection` is set,
+/// in the `'connection'` scope.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Counter {
+    vfs_idx: usize,
+    connection: bool,
+    filetype: FileType,
+    field: StatField,
+}
+
+impl Counter {
+    /// Each VFS has the counters of the `'process'` scope followed by the ones
+    /// of the `'connection'` scope.
+    fn rowid(self) -> sqlite3_int64 {
+        let counter = self.filetype as usize * StatField::ALL.len() + self.field as usize;
+        ((self.vfs_idx * 2 + self.connection as usize) * COUNTERS + counter) as _
+    }
+
+    /// Maps a `vtabstat` rowid back to the counter it was produced from by
+    /// [`VtabRowid`].
+    fn from_rowid(rowid: sqlite3_int64) -> Option<Self> {
+        let rowid: usize = rowid.try_into().ok()?;
+        let counter = rowid % COUNTERS;
+        Some(Self {
+            vfs_idx: rowid / COUNTERS / 2,
+            connection: rowid / COUNTERS % 2 == 1,
+            filetype: FileType::ALL[counter / StatField::ALL.len()],
+            field: StatField::ALL[counter % StatField::ALL.len()],
+        })
+    }
 }
 
 #[repr(C)]
@@ -23,6 +95,11 @@ pub struct VTab {
     _unused: [u8; 0],
 }
 
+/// Connects to a `vtabstat` table.
+///
+/// # Safety
+///
+/// `db` must be an open connection and `ppVTab` must be valid for writes.
 #[no_mangle]
 pub unsafe extern "C" fn VtabConnect(
     db: *mut sqlite3,
@@ -35,31 +112,94 @@ pub unsafe extern "C" fn VtabConnect(
     let rc = unsafe {
         ((*crate::API).declare_vtab.unwrap())(
             db,
-            b"CREATE TABLE x(file,stat,count)\0".as_ptr() as _,
+            b"CREATE TABLE x(file,stat,count,file_arg HIDDEN,stat_arg HIDDEN,scope HIDDEN,\
+vfs HIDDEN)\0"
+                .as_ptr() as _,
         )
     };
     if rc == SQLITE_OK as _ {
-        let pNew: Box<sqlite3_vtab> = Box::new(sqlite3_vtab {
-            pModule: core::ptr::null_mut(),
-            nRef: 0,
-            zErrMsg: core::ptr::null_mut(),
+        let pNew: Box<VfsStatVtab> = Box::new(VfsStatVtab {
+            base: sqlite3_vtab {
+                pModule: core::ptr::null_mut(),
+                nRef: 0,
+                zErrMsg: core::ptr::null_mut(),
+            },
+            db,
         });
-        unsafe { *ppVTab = Box::into_raw(pNew) };
+        unsafe { *ppVTab = Box::into_raw(pNew) as _ };
     }
     rc
 }
 
+/// Equality (and so `IN`) constraints on `file`, `stat`, `scope` and `vfs` are
+/// passed to [`VtabFilter`] in that order, with the matching
+/// `*_CONSTRAINT` bits set in `idxNum`.
 unsafe extern "C" fn VtabBestIndex(
     _pVTab: *mut sqlite3_vtab,
-    _arg1: *mut sqlite3_index_info,
+    arg1: *mut sqlite3_index_info,
 ) -> ::core::ffi::c_int {
+    let info = unsafe { &mut *arg1 };
+    // Index in aConstraint of the constraint used for file, stat, scope and
+    // vfs.
+    let mut used: [Option<usize>; 4] = [None; 4];
+    for i in 0..info.nConstraint as usize {
+        let constraint = unsafe { &*info.aConstraint.add(i) };
+        if constraint.op != SQLITE_INDEX_CONSTRAINT_EQ as _ || constraint.usable == 0 {
+            continue;
+        }
+        let slot = match constraint.iColumn {
+            FILE_COLUMN | FILE_ARG_COLUMN => 0,
+            STAT_COLUMN | STAT_ARG_COLUMN => 1,
+            SCOPE_COLUMN => 2,
+            VFS_COLUMN => 3,
+            _ => continue,
+        };
+        used[slot].get_or_insert(i);
+    }
+    let mut argv_index = 0;
+    for (slot, i) in used.iter().enumerate() {
+        if let Some(i) = i {
+            argv_index += 1;
+            let usage = unsafe { &mut *info.aConstraintUsage.add(*i) };
+            usage.argvIndex = argv_index;
+            usage.omit = 1;
+            info.idxNum |= 1 << slot;
+        }
+    }
+    let rows = match (used[0].is_some(), used[1].is_some()) {
+        (true, true) => {
+            info.idxFlags |= SQLITE_INDEX_SCAN_UNIQUE as ::core::ffi::c_int;
+            1
+        }
+        (true, false) => StatField::ALL.len(),
+        (false, true) => FileType::ALL.len(),
+        (false, false) => FileType::ALL.len() * StatField::ALL.len(),
+    };
+    info.estimatedRows = rows as _;
+    info.estimatedCost = rows as f64;
+    // Rows come out in rowid order, unless `IN` constraints make SQLite call
+    // xFilter once per value.
+    let order_by =
+        unsafe { core::slice::from_raw_parts(info.aOrderBy, info.nOrderBy.max(0) as usize) };
+    if used[0].is_none()
+        && used[1].is_none()
+        && !order_by.is_empty()
+        && order_by.iter().all(|o| o.iColumn == -1 && o.desc == 0)
+    {
+        info.orderByConsumed = 1;
+    }
     SQLITE_OK as _
 }
 
+/// Frees a table connected by [`VtabConnect`].
+///
+/// # Safety
+///
+/// `pVTab` must come from [`VtabConnect`] and must not be used afterwards.
 #[no_mangle]
 pub unsafe extern "C" fn VtabDisconnect(pVTab: *mut sqlite3_vtab) -> ::core::ffi::c_int {
     debug_assert!(!pVTab.is_null());
-    let _pNew: Box<sqlite3_vtab> = unsafe { Box::from_raw(pVTab) };
+    let _pNew: Box<VfsStatVtab> = unsafe { Box::from_raw(pVTab as *mut VfsStatVtab) };
     SQLITE_OK as _
 }
 
@@ -69,8 +209,13 @@ extern "C" fn VtabOpen(
 ) -> ::core::ffi::c_int {
     let cursor: Box<VfsStatCursor> = Box::new(VfsStatCursor {
         base: sqlite3_vtab_cursor { pVtab: pVTab },
+        vfs: None,
         filetype: FileType::Main,
         field: StatField::BytesIn,
+        eof: false,
+        only_file: None,
+        only_field: None,
+        connection: None,
     });
     unsafe { *ppCursor = Box::into_raw(cursor) as _ };
     SQLITE_OK as _
@@ -82,110 +227,137 @@ extern "C" fn VtabClose(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int {
     SQLITE_OK as _
 }
 
-/// Only a full table scan is supported.  So xFilter simply rewinds to the
-/// beginning.
+/// Rewinds to the first counter matching the constraints chosen by
+/// [`VtabBestIndex`], after selecting the counters of the requested scope.
+///
+/// # Safety
+///
+/// `arg1` must be a cursor opened by [`VtabOpen`], and `argv` must hold `argc`
+/// values.
 #[no_mangle]
 pub unsafe extern "C" fn VtabFilter(
     arg1: *mut sqlite3_vtab_cursor,
-    _idxNum: ::core::ffi::c_int,
+    idxNum: ::core::ffi::c_int,
     _idxStr: *const ::core::ffi::c_char,
-    _argc: ::core::ffi::c_int,
-    _argv: *mut *mut sqlite3_value,
+    argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
 ) -> ::core::ffi::c_int {
     let mut ptr = core::ptr::NonNull::new(arg1 as *mut VfsStatCursor).unwrap();
     let cur: &mut VfsStatCursor = unsafe { ptr.as_mut() };
-    cur.filetype = FileType::Main;
-    cur.field = StatField::BytesIn;
+    let vtab = unsafe { &mut *(cur.base.pVtab as *mut VfsStatVtab) };
+    let argv = if argc > 0 {
+        unsafe { core::slice::from_raw_parts(argv, argc as usize) }
+    } else {
+        &[]
+    };
+    let mut args = argv.iter().map(|value| {
+        let text = unsafe { ((*crate::API).value_text.unwrap())(*value) };
+        if text.is_null() {
+            None
+        } else {
+            Some(unsafe { core::ffi::CStr::from_ptr(text as _) }.to_bytes())
+        }
+    });
+    // A name that matches no counter, or NULL, selects no rows.
+    let mut matches = true;
+    cur.only_file = None;
+    if idxNum & FILE_CONSTRAINT != 0 {
+        cur.only_file = args.next().flatten().and_then(FileType::from_name);
+        matches &= cur.only_file.is_some();
+    }
+    cur.only_field = None;
+    if idxNum & STAT_CONSTRAINT != 0 {
+        cur.only_field = args.next().flatten().and_then(StatField::from_name);
+        matches &= cur.only_field.is_some();
+    }
+    let scope = if idxNum & SCOPE_CONSTRAINT != 0 {
+        args.next().flatten()
+    } else {
+        Some(&b"process"[..])
+    };
+    let vfs_ = if idxNum & VFS_CONSTRAINT != 0 {
+        let name = args.next().flatten().unwrap_or_default();
+        let name = String::from_utf8_lossy(name);
+        Vfs::find_named(&name).ok_or_else(|| format!("no stat VFS named {:?}", name))
+    } else {
+        unsafe { Vfs::of_connection(vtab.db) }
+            .or_else(Vfs::find)
+            .ok_or_else(|| "stat vfs is not registered".into())
+    };
+    let vfs_ = vfs_.and_then(|vfs_| match scope {
+        Some(b"process") => Ok((vfs_, None)),
+        Some(b"connection") => Ok((vfs_, Some(vfs_.connections.get(vtab.db)))),
+        _ => Err("scope must be 'process' or 'connection'".into()),
+    });
+    match vfs_ {
+        Ok((vfs_, connection)) => {
+            cur.vfs = Some(vfs_);
+            cur.connection = connection;
+        }
+        Err(err) => {
+            if let Some(ptr) = crate::err_to_sqlite3_str(err) {
+                unsafe { ((*crate::API).free.unwrap())(vtab.base.zErrMsg as _) };
+                vtab.base.zErrMsg = ptr;
+            }
+            return SQLITE_ERROR as _;
+        }
+    }
+    cur.filetype = cur.only_file.unwrap_or(FileType::Main);
+    cur.field = cur.only_field.unwrap_or(StatField::BytesIn);
+    cur.eof = !matches;
     SQLITE_OK as _
 }
 
+/// Advances through the fields of a file type and then through the file
+/// types, skipping the ones excluded by [`VtabFilter`].
+///
+/// # Safety
+///
+/// `arg1` must be a cursor opened by [`VtabOpen`].
 #[no_mangle]
 pub unsafe extern "C" fn VtabNext(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int {
     let mut ptr = core::ptr::NonNull::new(arg1 as *mut VfsStatCursor).unwrap();
     let cur: &mut VfsStatCursor = unsafe { ptr.as_mut() };
-    match cur.field {
-        StatField::BytesIn => {
-            cur.field = StatField::BytesOut;
-        }
-        StatField::BytesOut => {
-            cur.field = StatField::Read;
-        }
-        StatField::Read => {
-            cur.field = StatField::Write;
-        }
-        StatField::Write => {
-            cur.field = StatField::Sync;
-        }
-        StatField::Sync => {
-            cur.field = StatField::Open;
-        }
-        StatField::Open => {
-            cur.field = StatField::Lock;
-        }
-        StatField::Lock => {
-            cur.field = StatField::Access;
-        }
-        StatField::Access => {
-            cur.field = StatField::Delete;
-        }
-        StatField::Delete => {
-            cur.field = StatField::FullPath;
-        }
-        StatField::FullPath => {
-            cur.field = StatField::Random;
-        }
-        StatField::Random => {
-            cur.field = StatField::Sleep;
-        }
-        StatField::Sleep => {
-            cur.field = StatField::CurrentTime;
-        }
-        StatField::CurrentTime => {
-            cur.field = StatField::BytesIn;
-            match cur.filetype {
-                FileType::Main => {
-                    cur.filetype = FileType::Journal;
-                }
-                FileType::Journal => {
-                    cur.filetype = FileType::Wal;
-                }
-                FileType::Wal => {
-                    cur.filetype = FileType::MasterJournal;
-                }
-                FileType::MasterJournal => {
-                    cur.filetype = FileType::SubJournal;
-                }
-                FileType::SubJournal => {
-                    cur.filetype = FileType::TempDb;
-                }
-                FileType::TempDb => {
-                    cur.filetype = FileType::TempJournal;
-                }
-                FileType::TempJournal => {
-                    cur.filetype = FileType::Transient;
-                }
-                FileType::Transient => {
-                    cur.filetype = FileType::Any;
-                }
-                FileType::Any => {
-                    cur.filetype = FileType::Main;
-                }
-            }
+    let next_field = match cur.only_field {
+        Some(_) => None,
+        None => StatField::ALL.get(cur.field as usize + 1),
+    };
+    if let Some(field) = next_field {
+        cur.field = *field;
+    } else {
+        cur.field = cur.only_field.unwrap_or(StatField::BytesIn);
+        let next_filetype = match cur.only_file {
+            Some(_) => None,
+            None => FileType::ALL.get(cur.filetype as usize + 1),
+        };
+        if let Some(filetype) = next_filetype {
+            cur.filetype = *filetype;
+        } else {
+            cur.filetype = cur.only_file.unwrap_or(FileType::Main);
+            cur.eof = true;
         }
     }
     SQLITE_OK as _
 }
 
+/// Returns whether the cursor is past the last counter.
+///
+/// # Safety
+///
+/// `arg1` must be a cursor opened by [`VtabOpen`].
 #[no_mangle]
 pub unsafe extern "C" fn VtabEof(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int {
     let mut ptr = core::ptr::NonNull::new(arg1 as *mut VfsStatCursor).unwrap();
     let cur: &mut VfsStatCursor = unsafe { ptr.as_mut() };
-    match (cur.filetype, cur.field) {
-        (FileType::Any, StatField::CurrentTime) => true as _,
-        _ => false as _,
-    }
+    cur.eof as _
 }
 
+/// Returns the value of `column` for the current counter.
+///
+/// # Safety
+///
+/// `arg1` must be a cursor opened by [`VtabOpen`] and `ctx` must be the context
+/// SQLite passed with it.
 #[no_mangle]
 pub unsafe extern "C" fn VtabColumn(
     arg1: *mut sqlite3_vtab_cursor,
@@ -195,90 +367,49 @@ pub unsafe extern "C" fn VtabColumn(
     let ptr = core::ptr::NonNull::new(arg1 as *mut VfsStatCursor).unwrap();
     let cur: &VfsStatCursor = unsafe { ptr.as_ref() };
     match column {
-        0 => {
+        FILE_COLUMN | FILE_ARG_COLUMN => {
             // VSTAT_COLUMN_FILE
-            unsafe {
-                ((*crate::API).result_text.unwrap())(
-                    ctx,
-                    match cur.filetype {
-                        FileType::Main => b"main\0".as_ptr() as _,
-                        FileType::Journal => b"journal\0".as_ptr() as _,
-                        FileType::Wal => b"wal\0".as_ptr() as _,
-                        FileType::MasterJournal => b"master-journal\0".as_ptr() as _,
-                        FileType::SubJournal => b"sub-journal\0".as_ptr() as _,
-                        FileType::TempDb => b"temp-database\0".as_ptr() as _,
-                        FileType::TempJournal => b"temp-journal\0".as_ptr() as _,
-                        FileType::Transient => b"transient-db\0".as_ptr() as _,
-                        FileType::Any => b"*\0".as_ptr() as _,
-                    },
-                    -1,
-                    None,
-                )
-            };
+            unsafe { crate::result_str(ctx, cur.filetype.name()) };
         }
-        1 => {
+        STAT_COLUMN | STAT_ARG_COLUMN => {
             // VSTAT_COLUMN_STAT
-            unsafe {
-                ((*crate::API).result_text.unwrap())(
-                    ctx,
-                    match cur.field {
-                        StatField::BytesIn => b"bytesIn\0".as_ptr() as _,
-                        StatField::BytesOut => b"bytesOut\0".as_ptr() as _,
-                        StatField::Read => b"read\0".as_ptr() as _,
-                        StatField::Write => b"write\0".as_ptr() as _,
-                        StatField::Sync => b"sync\0".as_ptr() as _,
-                        StatField::Open => b"open\0".as_ptr() as _,
-                        StatField::Lock => b"lock\0".as_ptr() as _,
-                        StatField::Access => b"access\0".as_ptr() as _,
-                        StatField::Delete => b"delete\0".as_ptr() as _,
-                        StatField::FullPath => b"fullPath\0".as_ptr() as _,
-                        StatField::Random => b"random\0".as_ptr() as _,
-                        StatField::Sleep => b"sleep\0".as_ptr() as _,
-                        StatField::CurrentTime => b"currentTime\0".as_ptr() as _,
-                    },
-                    -1,
-                    None,
-                )
-            };
+            unsafe { crate::result_str(ctx, cur.field.name()) };
         }
-        2 => {
+        COUNT_COLUMN => {
             //VSTAT_COLUMN_COUNT
-            let vfs: *mut sqlite3_vfs =
-                unsafe { ((*crate::API).vfs_find.unwrap())(crate::vfs::VFS_NAME.as_ptr() as _) };
-            debug_assert!(!vfs.is_null());
-            let vfs = core::ptr::NonNull::new(vfs).unwrap();
-            let vfs_ptr: core::ptr::NonNull<crate::vfs::Vfs> =
-                core::ptr::NonNull::new(unsafe { vfs.as_ref() }.pAppData as *mut crate::vfs::Vfs)
-                    .expect("pAppData of stat vfs is null");
-            let vfs_ = unsafe { vfs_ptr.as_ref() };
+            let vfs_ = cur.vfs.expect("vtabstat cursor was not filtered");
+            let file_stats = cur.connection.as_deref().unwrap_or(&vfs_.file_stats);
             unsafe {
                 ((*crate::API).result_int64.unwrap())(
                     ctx,
-                    match cur.field {
-                        StatField::BytesIn => *statcnt!(vfs_.file_stats, cur.filetype, BytesIn),
-                        StatField::BytesOut => *statcnt!(vfs_.file_stats, cur.filetype, BytesOut),
-                        StatField::Read => *statcnt!(vfs_.file_stats, cur.filetype, Read),
-                        StatField::Write => *statcnt!(vfs_.file_stats, cur.filetype, Write),
-                        StatField::Sync => *statcnt!(vfs_.file_stats, cur.filetype, Sync),
-                        StatField::Open => *statcnt!(vfs_.file_stats, cur.filetype, Open),
-                        StatField::Lock => *statcnt!(vfs_.file_stats, cur.filetype, Lock),
-                        StatField::Access => *statcnt!(vfs_.file_stats, cur.filetype, Access),
-                        StatField::Delete => *statcnt!(vfs_.file_stats, cur.filetype, Delete),
-                        StatField::FullPath => *statcnt!(vfs_.file_stats, cur.filetype, FullPath),
-                        StatField::Random => *statcnt!(vfs_.file_stats, cur.filetype, Random),
-                        StatField::Sleep => *statcnt!(vfs_.file_stats, cur.filetype, Sleep),
-                        StatField::CurrentTime => {
-                            *statcnt!(vfs_.file_stats, cur.filetype, CurrentTime)
-                        }
-                    } as i64,
+                    file_stats.file(cur.filetype).get(cur.field) as i64,
                 );
             }
         }
+        SCOPE_COLUMN => {
+            let scope = if cur.connection.is_some() {
+                "connection"
+            } else {
+                "process"
+            };
+            unsafe { crate::result_str(ctx, scope) };
+        }
+        VFS_COLUMN => {
+            if let Some(vfs_) = cur.vfs {
+                unsafe { crate::result_str(ctx, vfs_.name()) };
+            }
+        }
         _ => unreachable!("Unknown column number {}", column),
     }
     SQLITE_OK as _
 }
 
+/// Stores the rowid of the current counter in `pRowid`.
+///
+/// # Safety
+///
+/// `arg1` must be a cursor opened by [`VtabOpen`] and `pRowid` must be valid
+/// for writes.
 #[no_mangle]
 pub unsafe extern "C" fn VtabRowid(
     arg1: *mut sqlite3_vtab_cursor,
@@ -286,22 +417,98 @@ pub unsafe extern "C" fn VtabRowid(
 ) -> ::core::ffi::c_int {
     let ptr = core::ptr::NonNull::new(arg1 as *mut VfsStatCursor).unwrap();
     let cur: &VfsStatCursor = unsafe { ptr.as_ref() };
-    let ftype_idx = cur.filetype as i64;
-    let field_idx = cur.field as i64;
-    unsafe {
-        *pRowid = ftype_idx * 13 + field_idx;
-    }
+    let vfs_idx = Vfs::instances()
+        .iter()
+        .position(|vfs_| cur.vfs.map_or(false, |vfs| core::ptr::eq(*vfs_, vfs)))
+        .unwrap_or(0);
+    let counter = Counter {
+        vfs_idx,
+        connection: cur.connection.is_some(),
+        filetype: cur.filetype,
+        field: cur.field,
+    };
+    unsafe { *pRowid = counter.rowid() };
     SQLITE_OK as _
 }
 
+/// `DELETE` resets the counters of the deleted rows to zero and `UPDATE` sets
+/// them to the new `count` value, like upstream `vfsstat.c`.  Inserting rows,
+/// changing the rowid or the `file` and `stat` of a row is not allowed.  The
+/// rowid also encodes the VFS and the scope of the counter, so that the rows of
+/// the `'connection'` scope change the counters of the connection.
+///
+/// # Safety
+///
+/// The arguments must be the ones SQLite passes to `xUpdate`: `argv` holds
+/// `argc` values.
 #[no_mangle]
 pub unsafe extern "C" fn VtabUpdate(
-    _arg1: *mut sqlite3_vtab,
-    _arg2: ::core::ffi::c_int,
-    _arg3: *mut *mut sqlite3_value,
-    _arg4: *mut sqlite3_int64,
+    arg1: *mut sqlite3_vtab,
+    argc: ::core::ffi::c_int,
+    argv: *mut *mut sqlite3_value,
+    _pRowid: *mut sqlite3_int64,
 ) -> ::core::ffi::c_int {
-    SQLITE_ERROR as _
+    let vtab = unsafe { &mut *(arg1 as *mut VfsStatVtab) };
+    let argv = unsafe { core::slice::from_raw_parts(argv, argc as usize) };
+    let value_int64 = unsafe { (*crate::API).value_int64.unwrap() };
+    let value_type = unsafe { (*crate::API).value_type.unwrap() };
+    let value_text = unsafe { (*crate::API).value_text.unwrap() };
+    let rowid = unsafe { value_int64(argv[0]) };
+    let counter = Counter::from_rowid(rowid)
+        .and_then(|counter| Some((counter, *Vfs::instances().get(counter.vfs_idx)?)));
+    let (counter, vfs_) = match counter {
+        Some(counter) if argc == 1 || unsafe { value_type(argv[0]) } == SQLITE_INTEGER as _ => {
+            counter
+        }
+        // argv[0] is NULL for INSERT.
+        _ => return SQLITE_ERROR as _,
+    };
+    let connection = if counter.connection {
+        Some(vfs_.connections.get(vtab.db))
+    } else {
+        None
+    };
+    let value = connection
+        .as_deref()
+        .unwrap_or(&vfs_.file_stats)
+        .file(counter.filetype)
+        .field(counter.field);
+    if argc == 1 {
+        // DELETE
+        value.store(0, Ordering::Relaxed);
+        return SQLITE_OK as _;
+    }
+    // UPDATE
+    if rowid != unsafe { value_int64(argv[1]) } {
+        return SQLITE_ERROR as _;
+    }
+    // argv[2..] are the new column values: file, stat, count and the hidden
+    // columns.
+    let text = |arg| {
+        let text = unsafe { value_text(arg) };
+        if text.is_null() {
+            None
+        } else {
+            Some(unsafe { core::ffi::CStr::from_ptr(text as _) }.to_bytes())
+        }
+    };
+    let err = if text(argv[2]) != Some(counter.filetype.name().as_bytes())
+        || text(argv[3]) != Some(counter.field.name().as_bytes())
+    {
+        "the file and stat of a counter cannot be changed"
+    } else if unsafe { value_type(argv[4]) } == SQLITE_NULL as _
+        || unsafe { value_int64(argv[4]) } < 0
+    {
+        "count must be a non-negative integer"
+    } else {
+        value.store(unsafe { value_int64(argv[4]) } as u64, Ordering::Relaxed);
+        return SQLITE_OK as _;
+    };
+    if let Some(ptr) = crate::err_to_sqlite3_str(err.into()) {
+        unsafe { ((*crate::API).free.unwrap())(vtab.base.zErrMsg as _) };
+        vtab.base.zErrMsg = ptr;
+    }
+    SQLITE_CONSTRAINT as _
 }
 
 impl VTab {
@@ -333,13 +540,20 @@ impl VTab {
         xShadowName: None,
     };
 
+    /// Registers the `vtabstat` module on `db`.  Its destructor tells
+    /// [`connections`](crate::connections) that `db` was closed.
+    ///
+    /// # Safety
+    ///
+    /// `db` must be an open connection.
     pub unsafe fn create(db: *mut sqlite3) -> Result<(), String> {
         let ret = unsafe {
-            ((*crate::API).create_module.unwrap())(
+            ((*crate::API).create_module_v2.unwrap())(
                 db,
                 Self::VTAB_NAME.as_ptr() as _,
                 &Self::VTAB_MODULE,
-                core::ptr::null_mut(),
+                db as _,
+                Some(crate::connections::closed),
             )
         };
         if ret != SQLITE_OK as _ {
@@ -348,3 +562,127 @@ impl VTab {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_rowid() {
+        let counters = [
+            (0, false, FileType::Main, StatField::BytesIn),
+            (0, true, FileType::Main, StatField::BytesIn),
+            (1, false, FileType::ALL[1], StatField::ALL[2]),
+            (
+                2,
+                true,
+                *FileType::ALL.last().unwrap(),
+                *StatField::ALL.last().unwrap(),
+            ),
+        ];
+        let mut rowids = Vec::new();
+        for (vfs_idx, connection, filetype, field) in counters {
+            let counter = Counter {
+                vfs_idx,
+                connection,
+                filetype,
+                field,
+            };
+            assert_eq!(Counter::from_rowid(counter.rowid()), Some(counter));
+            rowids.push(counter.rowid());
+        }
+        rowids.dedup();
+        assert_eq!(rowids.len(), counters.len());
+        assert_eq!(Counter::from_rowid(-1), None);
+    }
+
+    /// Loads the extension on the first connection opened, like `.load` would.
+    unsafe extern "C" fn load(
+        db: *mut sqlite3,
+        pzErrMsg: *mut *mut ::core::ffi::c_char,
+        pApi: *mut crate::sqlite3ext::sqlite3_api_routines,
+    ) -> ::core::ffi::c_int {
+        static LOAD: std::sync::Once = std::sync::Once::new();
+        let mut rc = SQLITE_OK as _;
+        LOAD.call_once(|| rc = unsafe { crate::sqlite3_vfsstatrs_init(db, pzErrMsg, pApi) });
+        if rc == crate::SQLITE_OK_LOAD_PERMANENTLY as _ {
+            SQLITE_OK as _
+        } else {
+            rc
+        }
+    }
+
+    /// Returns the number of writes of the `'connection'` scope of `db`.
+    unsafe fn connection_writes(db: *mut libsqlite3_sys::sqlite3) -> i64 {
+        let mut stmt = core::ptr::null_mut();
+        let sql =
+            b"SELECT sum(count) FROM vtabstat WHERE stat = 'write' AND scope = 'connection'\0";
+        unsafe {
+            assert_eq!(
+                libsqlite3_sys::sqlite3_prepare_v2(
+                    db,
+                    sql.as_ptr() as _,
+                    -1,
+                    &mut stmt,
+                    core::ptr::null_mut()
+                ),
+                libsqlite3_sys::SQLITE_OK
+            );
+            assert_eq!(
+                libsqlite3_sys::sqlite3_step(stmt),
+                libsqlite3_sys::SQLITE_ROW
+            );
+            let writes = libsqlite3_sys::sqlite3_column_int64(stmt, 0);
+            libsqlite3_sys::sqlite3_finalize(stmt);
+            writes
+        }
+    }
+
+    #[test]
+    fn test_connection_scope() {
+        let dir = std::env::temp_dir().join(format!("vfsstat-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let open = |name: &str| {
+            let path = alloc::ffi::CString::new(dir.join(name).to_str().unwrap()).unwrap();
+            let mut db = core::ptr::null_mut();
+            let rc = unsafe {
+                libsqlite3_sys::sqlite3_open_v2(
+                    path.as_ptr(),
+                    &mut db,
+                    libsqlite3_sys::SQLITE_OPEN_READWRITE | libsqlite3_sys::SQLITE_OPEN_CREATE,
+                    core::ptr::null(),
+                )
+            };
+            assert_eq!(rc, libsqlite3_sys::SQLITE_OK);
+            db
+        };
+        unsafe {
+            libsqlite3_sys::sqlite3_auto_extension(Some(core::mem::transmute::<
+                *const (),
+                unsafe extern "C" fn(),
+            >(load as *const ())));
+        }
+        // The extension makes its VFS the default when it is loaded, so the
+        // first connection does not use it.
+        let first = open("first.db");
+        let (a, b) = (open("a.db"), open("b.db"));
+        let (a_writes, b_writes) = unsafe { (connection_writes(a), connection_writes(b)) };
+        let rc = unsafe {
+            libsqlite3_sys::sqlite3_exec(
+                a,
+                b"CREATE TABLE t(x); INSERT INTO t VALUES (1);\0".as_ptr() as _,
+                None,
+                core::ptr::null_mut(),
+                core::ptr::null_mut(),
+            )
+        };
+        assert_eq!(rc, libsqlite3_sys::SQLITE_OK);
+        // The main database and the journal of a were written.
+        assert!(unsafe { connection_writes(a) } >= a_writes + 2);
+        assert_eq!(unsafe { connection_writes(b) }, b_writes);
+        for db in [first, a, b] {
+            unsafe { libsqlite3_sys::sqlite3_close(db) };
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! The `vfsstat_wide` table: the `vtabstat` counters with one row per file
+//! type and one column per stat.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    table::{Row, Rows, Table},
+    vfs::Vfs,
+    FileType, StatField,
+};
+
+/// Columns after `file` are in [`StatField::ALL`] order.
+pub static TABLE: Table = Table {
+    name: b"vfsstat_wide\0",
+    schema: b"CREATE TABLE x(file TEXT,bytesIn INTEGER,bytesOut INTEGER,read INTEGER,\
+write INTEGER,sync INTEGER,open INTEGER,lock INTEGER,access INTEGER,\"delete\" INTEGER,\
+fullPath INTEGER,random INTEGER,sleep INTEGER,currentTime INTEGER,truncate INTEGER,\
+fileSize INTEGER,fileControl INTEGER,close INTEGER,unlock INTEGER,\
+checkReservedLock INTEGER,shmMap INTEGER,shmLock INTEGER,shmBarrier INTEGER,\
+shmUnmap INTEGER,fetch INTEGER,unfetch INTEGER,bytesFetched INTEGER)\0",
+    rows: Rows::Scan(rows),
+};
+
+fn rows(vfs_: &Vfs) -> Vec<Row> {
+    FileType::ALL
+        .iter()
+        .map(|filetype| {
+            let stats = vfs_.file_stats.file(*filetype);
+            let mut row = vec![filetype.name().into()];
+            row.extend(StatField::ALL.iter().map(|field| stats.get(*field).into()));
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_follow_stat_fields() {
+        let schema = core::str::from_utf8(TABLE.schema).unwrap();
+        let columns: Vec<&str> = schema
+            .trim_start_matches("CREATE TABLE x(")
+            .trim_end_matches(")\0")
+            .split(',')
+            .map(|column| column.split(' ').next().unwrap().trim_matches('"'))
+            .collect();
+        let mut expected = vec!["file"];
+        expected.extend(StatField::ALL.iter().map(|field| field.name()));
+        assert_eq!(columns, expected);
+    }
+}